@@ -0,0 +1,295 @@
+// benches/search.rs
+//! Search latency benchmarks, run with `cargo bench --bench search`
+//!
+//! Besides the Criterion benchmarks, a report prints the p50 and p99 latency and the
+//! allocations of every benchmarked query, counted by a global allocator.
+
+use criterion::{black_box, BenchmarkId, Criterion};
+use emoji_search::constants::{load_emoji_data, EmojiData, Options};
+use emoji_search::{search_best_matching_emojis, search_emojis};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+
+/// Words typed one character at a time, for prefix queries of length 1 to 5
+const PREFIX_WORDS: [&str; 4] = ["smile", "heart", "party", "flags"];
+
+const MULTIPLE_WORDS_QUERIES: [&str; 4] = [
+    "smiling face",
+    "red heart",
+    "face with tears of joy",
+    "woman firefighter medium skin",
+];
+
+const BEST_MATCHING_QUERIES: [&str; 4] = [
+    "dancing",
+    "crying faces",
+    "going to the beach with my dog",
+    "happy birthday party",
+];
+
+const MAX_LIMITS: [u32; 3] = [5, 24, 100];
+
+/// Number of runs of every query in the latency report
+const NUM_LATENCY_RUNS: usize = 200;
+
+/// Counts allocations, so the latency report can show allocations per query
+struct CountingAllocator;
+
+static NUM_ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static NUM_ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        NUM_ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        NUM_ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Custom keywords like a user dictionary would add
+fn custom_options() -> Options {
+    let custom_emoji_keywords = [
+        ("🐶", vec!["pupper", "doggo", "beach buddy"]),
+        ("🎉", vec!["birthday", "yay", "celebrate"]),
+        ("😭", vec!["sob", "crying faces"]),
+        ("💃", vec!["dancing queen", "salsa"]),
+    ]
+    .into_iter()
+    .filter_map(|(emoji, keywords)| {
+        let keywords = keywords.into_iter().map(|k| k.to_string()).collect();
+        emojis::get(emoji).map(|emoji| (emoji, keywords))
+    })
+    .collect::<HashMap<_, _>>();
+
+    Options {
+        custom_emoji_keywords: Some(custom_emoji_keywords),
+        ..Options::default()
+    }
+}
+
+fn bench_load_emoji_data(c: &mut Criterion) {
+    c.bench_function("load_emoji_data", |b| {
+        b.iter(|| load_emoji_data().expect("failed to load emoji data"))
+    });
+}
+
+fn bench_single_word_prefixes(c: &mut Criterion, runtime: &Runtime, emoji_data: &EmojiData) {
+    let mut group = c.benchmark_group("single_word_prefix");
+
+    for max_limit in MAX_LIMITS {
+        for len in 1..=5 {
+            group.bench_with_input(
+                BenchmarkId::new(format!("limit_{}", max_limit), format!("len_{}", len)),
+                &len,
+                |b, &len| {
+                    b.to_async(runtime).iter(|| async move {
+                        for word in PREFIX_WORDS {
+                            black_box(
+                                search_emojis(&word[..len], Some(max_limit), None, emoji_data)
+                                    .await,
+                            )
+                            .ok();
+                        }
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_multiple_words(c: &mut Criterion, runtime: &Runtime, emoji_data: &EmojiData) {
+    let mut group = c.benchmark_group("multiple_words");
+
+    for max_limit in MAX_LIMITS {
+        for query in MULTIPLE_WORDS_QUERIES {
+            group.bench_with_input(
+                BenchmarkId::new(format!("limit_{}", max_limit), query),
+                query,
+                |b, query| {
+                    b.to_async(runtime).iter(|| async move {
+                        black_box(search_emojis(query, Some(max_limit), None, emoji_data).await)
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_best_matching(c: &mut Criterion, runtime: &Runtime, emoji_data: &EmojiData) {
+    let mut group = c.benchmark_group("best_matching");
+
+    for (options_name, options) in [
+        ("default", None),
+        ("custom_keywords", Some(custom_options())),
+    ] {
+        for max_limit in MAX_LIMITS {
+            for query in BEST_MATCHING_QUERIES {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("{}/limit_{}", options_name, max_limit), query),
+                    query,
+                    |b, query| {
+                        b.to_async(runtime).iter(|| {
+                            let options = options.clone();
+                            async move {
+                                black_box(
+                                    search_best_matching_emojis(
+                                        query,
+                                        Some(max_limit),
+                                        options,
+                                        emoji_data,
+                                    )
+                                    .await,
+                                )
+                            }
+                        })
+                    },
+                );
+            }
+        }
+    }
+
+    group.finish();
+}
+
+/// Latency percentiles and mean allocations of one query
+struct LatencyStats {
+    p50: Duration,
+    p99: Duration,
+    num_allocations: u64,
+    num_bytes: u64,
+}
+
+/// Run a search `NUM_LATENCY_RUNS` times, measuring its latency and allocations
+fn measure<F, Fut>(runtime: &Runtime, search: F) -> LatencyStats
+where
+    F: Fn() -> Fut,
+    Fut: Future,
+{
+    let mut latencies: Vec<Duration> = Vec::with_capacity(NUM_LATENCY_RUNS);
+
+    let allocations_before = NUM_ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes_before = NUM_ALLOCATED_BYTES.load(Ordering::Relaxed);
+
+    for _ in 0..NUM_LATENCY_RUNS {
+        let start = Instant::now();
+        black_box(runtime.block_on(search()));
+        latencies.push(start.elapsed());
+    }
+
+    let num_allocations =
+        (NUM_ALLOCATIONS.load(Ordering::Relaxed) - allocations_before) / NUM_LATENCY_RUNS as u64;
+    let num_bytes =
+        (NUM_ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes_before) / NUM_LATENCY_RUNS as u64;
+
+    latencies.sort();
+    LatencyStats {
+        p50: percentile(&latencies, 50),
+        p99: percentile(&latencies, 99),
+        num_allocations,
+        num_bytes,
+    }
+}
+
+fn print_latency_row(group: &str, query: &str, stats: LatencyStats) {
+    println!(
+        "{:<40} {:<32} {:>12?} {:>12?} {:>14} {:>14}",
+        group, query, stats.p50, stats.p99, stats.num_allocations, stats.num_bytes
+    );
+}
+
+/// Print the p50 and p99 latency and the mean allocations of the same queries as the
+/// benchmarks, which Criterion doesn't report
+fn report_latency(runtime: &Runtime, emoji_data: &EmojiData) {
+    println!();
+    println!(
+        "{:<40} {:<32} {:>12} {:>12} {:>14} {:>14}",
+        "group", "query", "p50", "p99", "allocations", "bytes"
+    );
+
+    // Every keystroke of the prefix words
+    for max_limit in MAX_LIMITS {
+        let group = format!("single_word_prefix/limit_{}", max_limit);
+        for word in PREFIX_WORDS {
+            for len in 1..=word.len() {
+                let query = &word[..len];
+                let stats = measure(runtime, || {
+                    search_emojis(query, Some(max_limit), None, emoji_data)
+                });
+                print_latency_row(&group, query, stats);
+            }
+        }
+    }
+
+    for max_limit in MAX_LIMITS {
+        let group = format!("multiple_words/limit_{}", max_limit);
+        for query in MULTIPLE_WORDS_QUERIES {
+            let stats = measure(runtime, || {
+                search_emojis(query, Some(max_limit), None, emoji_data)
+            });
+            print_latency_row(&group, query, stats);
+        }
+    }
+
+    for (options_name, options) in [
+        ("default", None),
+        ("custom_keywords", Some(custom_options())),
+    ] {
+        for max_limit in MAX_LIMITS {
+            let group = format!("best_matching/{}/limit_{}", options_name, max_limit);
+            for query in BEST_MATCHING_QUERIES {
+                let stats = measure(runtime, || {
+                    search_best_matching_emojis(query, Some(max_limit), options.clone(), emoji_data)
+                });
+                print_latency_row(&group, query, stats);
+            }
+        }
+    }
+}
+
+/// Get a percentile of sorted latencies
+fn percentile(sorted_latencies: &[Duration], percentile: usize) -> Duration {
+    let idx = (sorted_latencies.len() * percentile)
+        .div_ceil(100)
+        .saturating_sub(1);
+    sorted_latencies[idx.min(sorted_latencies.len() - 1)]
+}
+
+fn main() {
+    let runtime = Runtime::new().expect("failed to start the tokio runtime");
+    let emoji_data = load_emoji_data().expect("failed to load emoji data");
+
+    let mut criterion = Criterion::default().configure_from_args();
+
+    bench_load_emoji_data(&mut criterion);
+    bench_single_word_prefixes(&mut criterion, &runtime, &emoji_data);
+    bench_multiple_words(&mut criterion, &runtime, &emoji_data);
+    bench_best_matching(&mut criterion, &runtime, &emoji_data);
+
+    criterion.final_summary();
+
+    // Skip the report when the benchmarks run as tests, e.g. `cargo test --all-targets`
+    if std::env::args().any(|arg| arg == "--bench") {
+        report_latency(&runtime, &emoji_data);
+    }
+}
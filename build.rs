@@ -0,0 +1,81 @@
+// build.rs
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The data files with emojis as strings, bundled for the wasm build
+///
+/// The field order must match `EmojiDataSnapshot` in `src/constants.rs`.
+#[derive(Serialize)]
+struct EmojiDataSnapshot {
+    emoji_keywords: HashMap<String, Vec<String>>,
+    keyword_most_relevant_emoji: HashMap<String, String>,
+    emoji_glossary: HashMap<String, Vec<String>>,
+    top_1000_words: Vec<String>,
+}
+
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // Only the wasm32 build loads the snapshot, the others embed the JSON files
+    if env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32") {
+        write_data_snapshot(&crate_dir, &out_dir);
+    }
+    generate_c_header(&crate_dir, &out_dir);
+}
+
+/// Encode the data files with bitcode, which is much smaller than the JSON
+fn write_data_snapshot(crate_dir: &Path, out_dir: &Path) {
+    let data_dir = crate_dir.join("data");
+    let snapshot = EmojiDataSnapshot {
+        emoji_keywords: read_json(&data_dir.join("emoogle-emoji-keywords.json")),
+        keyword_most_relevant_emoji: read_json(
+            &data_dir.join("emoogle-keyword-most-relevant-emoji.json"),
+        ),
+        emoji_glossary: read_json(&data_dir.join("emoogle-emoji-glossary.json")),
+        top_1000_words: read_json(&data_dir.join("top-1000-words-by-frequency.json")),
+    };
+
+    let bytes = bitcode::serialize(&snapshot).expect("Failed to encode emoji data snapshot");
+    fs::write(out_dir.join("emoji-data.bitcode"), bytes)
+        .expect("Failed to write emoji data snapshot");
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> T {
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let json = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+    serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Generate the C header of the plain C ABI in `src/capi.rs` as `emoji_search.h` next to the
+/// cdylib and staticlib, e.g. `target/release/emoji_search.h`
+fn generate_c_header(crate_dir: &Path, out_dir: &Path) {
+    // The header also has the docs of `EmojiData` and the variants of `FfiError`
+    for path in [
+        "src/capi.rs",
+        "src/constants.rs",
+        "src/error.rs",
+        "cbindgen.toml",
+    ] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("Failed to read cbindgen.toml");
+    let bindings = cbindgen::generate_with_config(crate_dir, config)
+        .unwrap_or_else(|e| panic!("Failed to generate the C header: {}", e));
+
+    // `OUT_DIR` is `<profile dir>/build/<package>-<hash>/out`
+    let profile_dir = out_dir
+        .ancestors()
+        .nth(3)
+        .expect("OUT_DIR outside of a target directory");
+    bindings.write_to_file(profile_dir.join("emoji_search.h"));
+}
@@ -1,1294 +0,0 @@
-// This file was autogenerated by some hot garbage in the `uniffi` crate.
-// Trust me, you don't want to mess with it!
-
-// swiftlint:disable all
-import Foundation
-
-// Depending on the consumer's build setup, the low-level FFI code
-// might be in a separate module, or it might be compiled inline into
-// this module. This is a bit of light hackery to work with both.
-#if canImport(emoji_searchFFI)
-import emoji_searchFFI
-#endif
-
-fileprivate extension RustBuffer {
-    // Allocate a new buffer, copying the contents of a `UInt8` array.
-    init(bytes: [UInt8]) {
-        let rbuf = bytes.withUnsafeBufferPointer { ptr in
-            RustBuffer.from(ptr)
-        }
-        self.init(capacity: rbuf.capacity, len: rbuf.len, data: rbuf.data)
-    }
-
-    static func empty() -> RustBuffer {
-        RustBuffer(capacity: 0, len:0, data: nil)
-    }
-
-    static func from(_ ptr: UnsafeBufferPointer<UInt8>) -> RustBuffer {
-        try! rustCall { ffi_emoji_search_rustbuffer_from_bytes(ForeignBytes(bufferPointer: ptr), $0) }
-    }
-
-    // Frees the buffer in place.
-    // The buffer must not be used after this is called.
-    func deallocate() {
-        try! rustCall { ffi_emoji_search_rustbuffer_free(self, $0) }
-    }
-}
-
-fileprivate extension ForeignBytes {
-    init(bufferPointer: UnsafeBufferPointer<UInt8>) {
-        self.init(len: Int32(bufferPointer.count), data: bufferPointer.baseAddress)
-    }
-}
-
-// For every type used in the interface, we provide helper methods for conveniently
-// lifting and lowering that type from C-compatible data, and for reading and writing
-// values of that type in a buffer.
-
-// Helper classes/extensions that don't change.
-// Someday, this will be in a library of its own.
-
-fileprivate extension Data {
-    init(rustBuffer: RustBuffer) {
-        self.init(
-            bytesNoCopy: rustBuffer.data!,
-            count: Int(rustBuffer.len),
-            deallocator: .none
-        )
-    }
-}
-
-// Define reader functionality.  Normally this would be defined in a class or
-// struct, but we use standalone functions instead in order to make external
-// types work.
-//
-// With external types, one swift source file needs to be able to call the read
-// method on another source file's FfiConverter, but then what visibility
-// should Reader have?
-// - If Reader is fileprivate, then this means the read() must also
-//   be fileprivate, which doesn't work with external types.
-// - If Reader is internal/public, we'll get compile errors since both source
-//   files will try define the same type.
-//
-// Instead, the read() method and these helper functions input a tuple of data
-
-fileprivate func createReader(data: Data) -> (data: Data, offset: Data.Index) {
-    (data: data, offset: 0)
-}
-
-// Reads an integer at the current offset, in big-endian order, and advances
-// the offset on success. Throws if reading the integer would move the
-// offset past the end of the buffer.
-fileprivate func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset: Data.Index)) throws -> T {
-    let range = reader.offset..<reader.offset + MemoryLayout<T>.size
-    guard reader.data.count >= range.upperBound else {
-        throw UniffiInternalError.bufferOverflow
-    }
-    if T.self == UInt8.self {
-        let value = reader.data[reader.offset]
-        reader.offset += 1
-        return value as! T
-    }
-    var value: T = 0
-    let _ = withUnsafeMutableBytes(of: &value, { reader.data.copyBytes(to: $0, from: range)})
-    reader.offset = range.upperBound
-    return value.bigEndian
-}
-
-// Reads an arbitrary number of bytes, to be used to read
-// raw bytes, this is useful when lifting strings
-fileprivate func readBytes(_ reader: inout (data: Data, offset: Data.Index), count: Int) throws -> Array<UInt8> {
-    let range = reader.offset..<(reader.offset+count)
-    guard reader.data.count >= range.upperBound else {
-        throw UniffiInternalError.bufferOverflow
-    }
-    var value = [UInt8](repeating: 0, count: count)
-    value.withUnsafeMutableBufferPointer({ buffer in
-        reader.data.copyBytes(to: buffer, from: range)
-    })
-    reader.offset = range.upperBound
-    return value
-}
-
-// Reads a float at the current offset.
-fileprivate func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
-    return Float(bitPattern: try readInt(&reader))
-}
-
-// Reads a float at the current offset.
-fileprivate func readDouble(_ reader: inout (data: Data, offset: Data.Index)) throws -> Double {
-    return Double(bitPattern: try readInt(&reader))
-}
-
-// Indicates if the offset has reached the end of the buffer.
-fileprivate func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
-    return reader.offset < reader.data.count
-}
-
-// Define writer functionality.  Normally this would be defined in a class or
-// struct, but we use standalone functions instead in order to make external
-// types work.  See the above discussion on Readers for details.
-
-fileprivate func createWriter() -> [UInt8] {
-    return []
-}
-
-fileprivate func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Sequence, S.Element == UInt8 {
-    writer.append(contentsOf: byteArr)
-}
-
-// Writes an integer in big-endian order.
-//
-// Warning: make sure what you are trying to write
-// is in the correct type!
-fileprivate func writeInt<T: FixedWidthInteger>(_ writer: inout [UInt8], _ value: T) {
-    var value = value.bigEndian
-    withUnsafeBytes(of: &value) { writer.append(contentsOf: $0) }
-}
-
-fileprivate func writeFloat(_ writer: inout [UInt8], _ value: Float) {
-    writeInt(&writer, value.bitPattern)
-}
-
-fileprivate func writeDouble(_ writer: inout [UInt8], _ value: Double) {
-    writeInt(&writer, value.bitPattern)
-}
-
-// Protocol for types that transfer other types across the FFI. This is
-// analogous to the Rust trait of the same name.
-fileprivate protocol FfiConverter {
-    associatedtype FfiType
-    associatedtype SwiftType
-
-    static func lift(_ value: FfiType) throws -> SwiftType
-    static func lower(_ value: SwiftType) -> FfiType
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType
-    static func write(_ value: SwiftType, into buf: inout [UInt8])
-}
-
-// Types conforming to `Primitive` pass themselves directly over the FFI.
-fileprivate protocol FfiConverterPrimitive: FfiConverter where FfiType == SwiftType { }
-
-extension FfiConverterPrimitive {
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lift(_ value: FfiType) throws -> SwiftType {
-        return value
-    }
-
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lower(_ value: SwiftType) -> FfiType {
-        return value
-    }
-}
-
-// Types conforming to `FfiConverterRustBuffer` lift and lower into a `RustBuffer`.
-// Used for complex types where it's hard to write a custom lift/lower.
-fileprivate protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustBuffer {}
-
-extension FfiConverterRustBuffer {
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lift(_ buf: RustBuffer) throws -> SwiftType {
-        var reader = createReader(data: Data(rustBuffer: buf))
-        let value = try read(from: &reader)
-        if hasRemaining(reader) {
-            throw UniffiInternalError.incompleteData
-        }
-        buf.deallocate()
-        return value
-    }
-
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lower(_ value: SwiftType) -> RustBuffer {
-          var writer = createWriter()
-          write(value, into: &writer)
-          return RustBuffer(bytes: writer)
-    }
-}
-// An error type for FFI errors. These errors occur at the UniFFI level, not
-// the library level.
-fileprivate enum UniffiInternalError: LocalizedError {
-    case bufferOverflow
-    case incompleteData
-    case unexpectedOptionalTag
-    case unexpectedEnumCase
-    case unexpectedNullPointer
-    case unexpectedRustCallStatusCode
-    case unexpectedRustCallError
-    case unexpectedStaleHandle
-    case rustPanic(_ message: String)
-
-    public var errorDescription: String? {
-        switch self {
-        case .bufferOverflow: return "Reading the requested value would read past the end of the buffer"
-        case .incompleteData: return "The buffer still has data after lifting its containing value"
-        case .unexpectedOptionalTag: return "Unexpected optional tag; should be 0 or 1"
-        case .unexpectedEnumCase: return "Raw enum value doesn't match any cases"
-        case .unexpectedNullPointer: return "Raw pointer value was null"
-        case .unexpectedRustCallStatusCode: return "Unexpected RustCallStatus code"
-        case .unexpectedRustCallError: return "CALL_ERROR but no errorClass specified"
-        case .unexpectedStaleHandle: return "The object in the handle map has been dropped already"
-        case let .rustPanic(message): return message
-        }
-    }
-}
-
-fileprivate extension NSLock {
-    func withLock<T>(f: () throws -> T) rethrows -> T {
-        self.lock()
-        defer { self.unlock() }
-        return try f()
-    }
-}
-
-fileprivate let CALL_SUCCESS: Int8 = 0
-fileprivate let CALL_ERROR: Int8 = 1
-fileprivate let CALL_UNEXPECTED_ERROR: Int8 = 2
-fileprivate let CALL_CANCELLED: Int8 = 3
-
-fileprivate extension RustCallStatus {
-    init() {
-        self.init(
-            code: CALL_SUCCESS,
-            errorBuf: RustBuffer.init(
-                capacity: 0,
-                len: 0,
-                data: nil
-            )
-        )
-    }
-}
-
-private func rustCall<T>(_ callback: (UnsafeMutablePointer<RustCallStatus>) -> T) throws -> T {
-    let neverThrow: ((RustBuffer) throws -> Never)? = nil
-    return try makeRustCall(callback, errorHandler: neverThrow)
-}
-
-private func rustCallWithError<T, E: Swift.Error>(
-    _ errorHandler: @escaping (RustBuffer) throws -> E,
-    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T) throws -> T {
-    try makeRustCall(callback, errorHandler: errorHandler)
-}
-
-private func makeRustCall<T, E: Swift.Error>(
-    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T,
-    errorHandler: ((RustBuffer) throws -> E)?
-) throws -> T {
-    uniffiEnsureEmojiSearchInitialized()
-    var callStatus = RustCallStatus.init()
-    let returnedVal = callback(&callStatus)
-    try uniffiCheckCallStatus(callStatus: callStatus, errorHandler: errorHandler)
-    return returnedVal
-}
-
-private func uniffiCheckCallStatus<E: Swift.Error>(
-    callStatus: RustCallStatus,
-    errorHandler: ((RustBuffer) throws -> E)?
-) throws {
-    switch callStatus.code {
-        case CALL_SUCCESS:
-            return
-
-        case CALL_ERROR:
-            if let errorHandler = errorHandler {
-                throw try errorHandler(callStatus.errorBuf)
-            } else {
-                callStatus.errorBuf.deallocate()
-                throw UniffiInternalError.unexpectedRustCallError
-            }
-
-        case CALL_UNEXPECTED_ERROR:
-            // When the rust code sees a panic, it tries to construct a RustBuffer
-            // with the message.  But if that code panics, then it just sends back
-            // an empty buffer.
-            if callStatus.errorBuf.len > 0 {
-                throw UniffiInternalError.rustPanic(try FfiConverterString.lift(callStatus.errorBuf))
-            } else {
-                callStatus.errorBuf.deallocate()
-                throw UniffiInternalError.rustPanic("Rust panic")
-            }
-
-        case CALL_CANCELLED:
-            fatalError("Cancellation not supported yet")
-
-        default:
-            throw UniffiInternalError.unexpectedRustCallStatusCode
-    }
-}
-
-private func uniffiTraitInterfaceCall<T>(
-    callStatus: UnsafeMutablePointer<RustCallStatus>,
-    makeCall: () throws -> T,
-    writeReturn: (T) -> ()
-) {
-    do {
-        try writeReturn(makeCall())
-    } catch let error {
-        callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
-    }
-}
-
-private func uniffiTraitInterfaceCallWithError<T, E>(
-    callStatus: UnsafeMutablePointer<RustCallStatus>,
-    makeCall: () throws -> T,
-    writeReturn: (T) -> (),
-    lowerError: (E) -> RustBuffer
-) {
-    do {
-        try writeReturn(makeCall())
-    } catch let error as E {
-        callStatus.pointee.code = CALL_ERROR
-        callStatus.pointee.errorBuf = lowerError(error)
-    } catch {
-        callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
-    }
-}
-fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
-    // All mutation happens with this lock held, which is why we implement @unchecked Sendable.
-    private let lock = NSLock()
-    private var map: [UInt64: T] = [:]
-    private var currentHandle: UInt64 = 1
-
-    func insert(obj: T) -> UInt64 {
-        lock.withLock {
-            let handle = currentHandle
-            currentHandle += 1
-            map[handle] = obj
-            return handle
-        }
-    }
-
-     func get(handle: UInt64) throws -> T {
-        try lock.withLock {
-            guard let obj = map[handle] else {
-                throw UniffiInternalError.unexpectedStaleHandle
-            }
-            return obj
-        }
-    }
-
-    @discardableResult
-    func remove(handle: UInt64) throws -> T {
-        try lock.withLock {
-            guard let obj = map.removeValue(forKey: handle) else {
-                throw UniffiInternalError.unexpectedStaleHandle
-            }
-            return obj
-        }
-    }
-
-    var count: Int {
-        get {
-            map.count
-        }
-    }
-}
-
-
-// Public interface members begin here.
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
-    typealias FfiType = UInt32
-    typealias SwiftType = UInt32
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
-        return try lift(readInt(&buf))
-    }
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        writeInt(&buf, lower(value))
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterBool : FfiConverter {
-    typealias FfiType = Int8
-    typealias SwiftType = Bool
-
-    public static func lift(_ value: Int8) throws -> Bool {
-        return value != 0
-    }
-
-    public static func lower(_ value: Bool) -> Int8 {
-        return value ? 1 : 0
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
-        return try lift(readInt(&buf))
-    }
-
-    public static func write(_ value: Bool, into buf: inout [UInt8]) {
-        writeInt(&buf, lower(value))
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterString: FfiConverter {
-    typealias SwiftType = String
-    typealias FfiType = RustBuffer
-
-    public static func lift(_ value: RustBuffer) throws -> String {
-        defer {
-            value.deallocate()
-        }
-        if value.data == nil {
-            return String()
-        }
-        let bytes = UnsafeBufferPointer<UInt8>(start: value.data!, count: Int(value.len))
-        return String(bytes: bytes, encoding: String.Encoding.utf8)!
-    }
-
-    public static func lower(_ value: String) -> RustBuffer {
-        return value.utf8CString.withUnsafeBufferPointer { ptr in
-            // The swift string gives us int8_t, we want uint8_t.
-            ptr.withMemoryRebound(to: UInt8.self) { ptr in
-                // The swift string gives us a trailing null byte, we don't want it.
-                let buf = UnsafeBufferPointer(rebasing: ptr.prefix(upTo: ptr.count - 1))
-                return RustBuffer.from(buf)
-            }
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
-        let len: Int32 = try readInt(&buf)
-        return String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
-    }
-
-    public static func write(_ value: String, into buf: inout [UInt8]) {
-        let len = Int32(value.utf8.count)
-        writeInt(&buf, len)
-        writeBytes(&buf, value.utf8)
-    }
-}
-
-
-
-
-/**
- * Core data structure containing all emoji data
- */
-public protocol EmojiDataProtocol: AnyObject, Sendable {
-    
-}
-/**
- * Core data structure containing all emoji data
- */
-open class EmojiData: EmojiDataProtocol, @unchecked Sendable {
-    fileprivate let pointer: UnsafeMutableRawPointer!
-
-    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public struct NoPointer {
-        public init() {}
-    }
-
-    // TODO: We'd like this to be `private` but for Swifty reasons,
-    // we can't implement `FfiConverter` without making this `required` and we can't
-    // make it `required` without making it `public`.
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
-        self.pointer = pointer
-    }
-
-    // This constructor can be used to instantiate a fake object.
-    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
-    //
-    // - Warning:
-    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public init(noPointer: NoPointer) {
-        self.pointer = nil
-    }
-
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_emoji_search_fn_clone_emojidata(self.pointer, $0) }
-    }
-    // No primary constructor declared for this class.
-
-    deinit {
-        guard let pointer = pointer else {
-            return
-        }
-
-        try! rustCall { uniffi_emoji_search_fn_free_emojidata(pointer, $0) }
-    }
-
-    
-
-    
-
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeEmojiData: FfiConverter {
-
-    typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = EmojiData
-
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> EmojiData {
-        return EmojiData(unsafeFromRawPointer: pointer)
-    }
-
-    public static func lower(_ value: EmojiData) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EmojiData {
-        let v: UInt64 = try readInt(&buf)
-        // The Rust code won't compile if a pointer won't fit in a UInt64.
-        // We have to go via `UInt` because that's the thing that's the size of a pointer.
-        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
-        if (ptr == nil) {
-            throw UniffiInternalError.unexpectedNullPointer
-        }
-        return try lift(ptr!)
-    }
-
-    public static func write(_ value: EmojiData, into buf: inout [UInt8]) {
-        // This fiddling is because `Int` is the thing that's the same size as a pointer.
-        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
-        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeEmojiData_lift(_ pointer: UnsafeMutableRawPointer) throws -> EmojiData {
-    return try FfiConverterTypeEmojiData.lift(pointer)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeEmojiData_lower(_ value: EmojiData) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeEmojiData.lower(value)
-}
-
-
-
-
-/**
- * Attributes for ranking emojis in single word search
- */
-public struct Attributes {
-    public var isExactMatch: Bool
-    public var isCustomMostRelevantEmoji: Bool
-    public var isMostRelevantEmoji: Bool
-    public var isEmojiName: Bool
-    public var isSingleWord: Bool
-    public var matchWord: String
-    public var prefixMatchRecentlySearchedInputsIdx: UInt32?
-    public var prefixMatchTop1000WordsIdx: UInt32?
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(isExactMatch: Bool, isCustomMostRelevantEmoji: Bool, isMostRelevantEmoji: Bool, isEmojiName: Bool, isSingleWord: Bool, matchWord: String, prefixMatchRecentlySearchedInputsIdx: UInt32?, prefixMatchTop1000WordsIdx: UInt32?) {
-        self.isExactMatch = isExactMatch
-        self.isCustomMostRelevantEmoji = isCustomMostRelevantEmoji
-        self.isMostRelevantEmoji = isMostRelevantEmoji
-        self.isEmojiName = isEmojiName
-        self.isSingleWord = isSingleWord
-        self.matchWord = matchWord
-        self.prefixMatchRecentlySearchedInputsIdx = prefixMatchRecentlySearchedInputsIdx
-        self.prefixMatchTop1000WordsIdx = prefixMatchTop1000WordsIdx
-    }
-}
-
-#if compiler(>=6)
-extension Attributes: Sendable {}
-#endif
-
-
-extension Attributes: Equatable, Hashable {
-    public static func ==(lhs: Attributes, rhs: Attributes) -> Bool {
-        if lhs.isExactMatch != rhs.isExactMatch {
-            return false
-        }
-        if lhs.isCustomMostRelevantEmoji != rhs.isCustomMostRelevantEmoji {
-            return false
-        }
-        if lhs.isMostRelevantEmoji != rhs.isMostRelevantEmoji {
-            return false
-        }
-        if lhs.isEmojiName != rhs.isEmojiName {
-            return false
-        }
-        if lhs.isSingleWord != rhs.isSingleWord {
-            return false
-        }
-        if lhs.matchWord != rhs.matchWord {
-            return false
-        }
-        if lhs.prefixMatchRecentlySearchedInputsIdx != rhs.prefixMatchRecentlySearchedInputsIdx {
-            return false
-        }
-        if lhs.prefixMatchTop1000WordsIdx != rhs.prefixMatchTop1000WordsIdx {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(isExactMatch)
-        hasher.combine(isCustomMostRelevantEmoji)
-        hasher.combine(isMostRelevantEmoji)
-        hasher.combine(isEmojiName)
-        hasher.combine(isSingleWord)
-        hasher.combine(matchWord)
-        hasher.combine(prefixMatchRecentlySearchedInputsIdx)
-        hasher.combine(prefixMatchTop1000WordsIdx)
-    }
-}
-
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeAttributes: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Attributes {
-        return
-            try Attributes(
-                isExactMatch: FfiConverterBool.read(from: &buf), 
-                isCustomMostRelevantEmoji: FfiConverterBool.read(from: &buf), 
-                isMostRelevantEmoji: FfiConverterBool.read(from: &buf), 
-                isEmojiName: FfiConverterBool.read(from: &buf), 
-                isSingleWord: FfiConverterBool.read(from: &buf), 
-                matchWord: FfiConverterString.read(from: &buf), 
-                prefixMatchRecentlySearchedInputsIdx: FfiConverterOptionUInt32.read(from: &buf), 
-                prefixMatchTop1000WordsIdx: FfiConverterOptionUInt32.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: Attributes, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.isExactMatch, into: &buf)
-        FfiConverterBool.write(value.isCustomMostRelevantEmoji, into: &buf)
-        FfiConverterBool.write(value.isMostRelevantEmoji, into: &buf)
-        FfiConverterBool.write(value.isEmojiName, into: &buf)
-        FfiConverterBool.write(value.isSingleWord, into: &buf)
-        FfiConverterString.write(value.matchWord, into: &buf)
-        FfiConverterOptionUInt32.write(value.prefixMatchRecentlySearchedInputsIdx, into: &buf)
-        FfiConverterOptionUInt32.write(value.prefixMatchTop1000WordsIdx, into: &buf)
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeAttributes_lift(_ buf: RustBuffer) throws -> Attributes {
-    return try FfiConverterTypeAttributes.lift(buf)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeAttributes_lower(_ value: Attributes) -> RustBuffer {
-    return FfiConverterTypeAttributes.lower(value)
-}
-
-
-/**
- * Options for customizing emoji search
- */
-public struct Options {
-    /**
-     * Custom emoji keywords to extend built-in keywords
-     */
-    public var customEmojiKeywords: [Emoji: [String]]?
-    /**
-     * Custom mappings from keywords to preferred emojis
-     */
-    public var customKeywordMostRelevantEmoji: [String: Emoji]?
-    /**
-     * Recently searched inputs for improved search suggestions
-     */
-    public var recentlySearchedInputs: [String]?
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Custom emoji keywords to extend built-in keywords
-         */customEmojiKeywords: [Emoji: [String]]?, 
-        /**
-         * Custom mappings from keywords to preferred emojis
-         */customKeywordMostRelevantEmoji: [String: Emoji]?, 
-        /**
-         * Recently searched inputs for improved search suggestions
-         */recentlySearchedInputs: [String]?) {
-        self.customEmojiKeywords = customEmojiKeywords
-        self.customKeywordMostRelevantEmoji = customKeywordMostRelevantEmoji
-        self.recentlySearchedInputs = recentlySearchedInputs
-    }
-}
-
-#if compiler(>=6)
-extension Options: Sendable {}
-#endif
-
-
-extension Options: Equatable, Hashable {
-    public static func ==(lhs: Options, rhs: Options) -> Bool {
-        if lhs.customEmojiKeywords != rhs.customEmojiKeywords {
-            return false
-        }
-        if lhs.customKeywordMostRelevantEmoji != rhs.customKeywordMostRelevantEmoji {
-            return false
-        }
-        if lhs.recentlySearchedInputs != rhs.recentlySearchedInputs {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(customEmojiKeywords)
-        hasher.combine(customKeywordMostRelevantEmoji)
-        hasher.combine(recentlySearchedInputs)
-    }
-}
-
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeOptions: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Options {
-        return
-            try Options(
-                customEmojiKeywords: FfiConverterOptionDictionaryTypeEmojiSequenceString.read(from: &buf), 
-                customKeywordMostRelevantEmoji: FfiConverterOptionDictionaryStringTypeEmoji.read(from: &buf), 
-                recentlySearchedInputs: FfiConverterOptionSequenceString.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: Options, into buf: inout [UInt8]) {
-        FfiConverterOptionDictionaryTypeEmojiSequenceString.write(value.customEmojiKeywords, into: &buf)
-        FfiConverterOptionDictionaryStringTypeEmoji.write(value.customKeywordMostRelevantEmoji, into: &buf)
-        FfiConverterOptionSequenceString.write(value.recentlySearchedInputs, into: &buf)
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOptions_lift(_ buf: RustBuffer) throws -> Options {
-    return try FfiConverterTypeOptions.lift(buf)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOptions_lower(_ value: Options) -> RustBuffer {
-    return FfiConverterTypeOptions.lower(value)
-}
-
-
-/**
- * Error type exposed through FFI
- * This implements all the necessary UniFFI traits
- */
-public enum FfiError: Swift.Error {
-
-    
-    
-    case Io
-    case Json
-    case Search(String
-    )
-    case InvalidInput(String
-    )
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeFfiError: FfiConverterRustBuffer {
-    typealias SwiftType = FfiError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
-
-        
-        case 1: return .Io
-        case 2: return .Json
-        case 3: return .Search(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InvalidInput(
-            try FfiConverterString.read(from: &buf)
-            )
-
-         default: throw UniffiInternalError.unexpectedEnumCase
-        }
-    }
-
-    public static func write(_ value: FfiError, into buf: inout [UInt8]) {
-        switch value {
-
-        
-
-        
-        
-        case .Io:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .Json:
-            writeInt(&buf, Int32(2))
-        
-        
-        case let .Search(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .InvalidInput(v1):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(v1, into: &buf)
-            
-        }
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeFfiError_lift(_ buf: RustBuffer) throws -> FfiError {
-    return try FfiConverterTypeFfiError.lift(buf)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeFfiError_lower(_ value: FfiError) -> RustBuffer {
-    return FfiConverterTypeFfiError.lower(value)
-}
-
-
-extension FfiError: Equatable, Hashable {}
-
-
-
-extension FfiError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
-    typealias SwiftType = UInt32?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt32.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt32.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionTypeOptions: FfiConverterRustBuffer {
-    typealias SwiftType = Options?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeOptions.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeOptions.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterSequenceString.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterSequenceString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionDictionaryStringTypeEmoji: FfiConverterRustBuffer {
-    typealias SwiftType = [String: Emoji]?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterDictionaryStringTypeEmoji.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterDictionaryStringTypeEmoji.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionDictionaryTypeEmojiSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [Emoji: [String]]?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterDictionaryTypeEmojiSequenceString.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterDictionaryTypeEmojiSequenceString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
-
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterString.write(item, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [String]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
-        }
-        return seq
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeEmoji: FfiConverterRustBuffer {
-    typealias SwiftType = [Emoji]
-
-    public static func write(_ value: [Emoji], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeEmoji.write(item, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Emoji] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [Emoji]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeEmoji.read(from: &buf))
-        }
-        return seq
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterDictionaryStringTypeEmoji: FfiConverterRustBuffer {
-    public static func write(_ value: [String: Emoji], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for (key, value) in value {
-            FfiConverterString.write(key, into: &buf)
-            FfiConverterTypeEmoji.write(value, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: Emoji] {
-        let len: Int32 = try readInt(&buf)
-        var dict = [String: Emoji]()
-        dict.reserveCapacity(Int(len))
-        for _ in 0..<len {
-            let key = try FfiConverterString.read(from: &buf)
-            let value = try FfiConverterTypeEmoji.read(from: &buf)
-            dict[key] = value
-        }
-        return dict
-    }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterDictionaryTypeEmojiSequenceString: FfiConverterRustBuffer {
-    public static func write(_ value: [Emoji: [String]], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for (key, value) in value {
-            FfiConverterTypeEmoji.write(key, into: &buf)
-            FfiConverterSequenceString.write(value, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Emoji: [String]] {
-        let len: Int32 = try readInt(&buf)
-        var dict = [Emoji: [String]]()
-        dict.reserveCapacity(Int(len))
-        for _ in 0..<len {
-            let key = try FfiConverterTypeEmoji.read(from: &buf)
-            let value = try FfiConverterSequenceString.read(from: &buf)
-            dict[key] = value
-        }
-        return dict
-    }
-}
-private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
-private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
-
-fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
-
-fileprivate func uniffiRustCallAsync<F, T>(
-    rustFutureFunc: () -> UInt64,
-    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
-    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
-    freeFunc: (UInt64) -> (),
-    liftFunc: (F) throws -> T,
-    errorHandler: ((RustBuffer) throws -> Swift.Error)?
-) async throws -> T {
-    // Make sure to call the ensure init function since future creation doesn't have a
-    // RustCallStatus param, so doesn't use makeRustCall()
-    uniffiEnsureEmojiSearchInitialized()
-    let rustFuture = rustFutureFunc()
-    defer {
-        freeFunc(rustFuture)
-    }
-    var pollResult: Int8;
-    repeat {
-        pollResult = await withUnsafeContinuation {
-            pollFunc(
-                rustFuture,
-                uniffiFutureContinuationCallback,
-                uniffiContinuationHandleMap.insert(obj: $0)
-            )
-        }
-    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
-
-    return try liftFunc(makeRustCall(
-        { completeFunc(rustFuture, $0) },
-        errorHandler: errorHandler
-    ))
-}
-
-// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
-// lift the return value or error and resume the suspended function.
-fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
-    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
-        continuation.resume(returning: pollResult)
-    } else {
-        print("uniffiFutureContinuationCallback invalid handle")
-    }
-}
-/**
- * Load emoji data from embedded JSON files
- */
-public func loadEmojiData()throws  -> EmojiData  {
-    return try  FfiConverterTypeEmojiData_lift(try rustCallWithError(FfiConverterTypeFfiError_lift) {
-    uniffi_emoji_search_fn_func_load_emoji_data($0
-    )
-})
-}
-/**
- * Search for best matching emojis
- *
- * This is a more forgiving search that would also match the stemmed input words
- * by stripping off suffixes, and handles parts of speech filtering.
- *
- * # Arguments
- * * `input` - The search query string
- * * `max_limit` - Maximum number of results to return (default: 24)
- * * `options` - Custom options for the search algorithm
- *
- * # Returns
- * A vector of best matching emoji strings
- */
-public func searchBestMatchingEmojis(input: String, maxLimit: UInt32?, options: Options?, emojiData: EmojiData)async throws  -> [Emoji]  {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_emoji_search_fn_func_search_best_matching_emojis(FfiConverterString.lower(input),FfiConverterOptionUInt32.lower(maxLimit),FfiConverterOptionTypeOptions.lower(options),FfiConverterTypeEmojiData_lower(emojiData)
-                )
-            },
-            pollFunc: ffi_emoji_search_rust_future_poll_rust_buffer,
-            completeFunc: ffi_emoji_search_rust_future_complete_rust_buffer,
-            freeFunc: ffi_emoji_search_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeEmoji.lift,
-            errorHandler: FfiConverterTypeFfiError_lift
-        )
-}
-/**
- * Main entry point for searching emojis
- *
- * Optimized for search-as-you-type experience. The more characters/words
- * a user types, the narrower the set of emojis returned.
- *
- * # Arguments
- * * `input` - The search query string
- * * `max_limit` - Maximum number of results to return (default: 24)
- * * `options` - Custom options for the search algorithm
- *
- * # Returns
- * A vector of matching emoji strings
- */
-public func searchEmojis(input: String, maxLimit: UInt32?, options: Options?, emojiData: EmojiData)async throws  -> [Emoji]  {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_emoji_search_fn_func_search_emojis(FfiConverterString.lower(input),FfiConverterOptionUInt32.lower(maxLimit),FfiConverterOptionTypeOptions.lower(options),FfiConverterTypeEmojiData_lower(emojiData)
-                )
-            },
-            pollFunc: ffi_emoji_search_rust_future_poll_rust_buffer,
-            completeFunc: ffi_emoji_search_rust_future_complete_rust_buffer,
-            freeFunc: ffi_emoji_search_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeEmoji.lift,
-            errorHandler: FfiConverterTypeFfiError_lift
-        )
-}
-
-private enum InitializationResult {
-    case ok
-    case contractVersionMismatch
-    case apiChecksumMismatch
-}
-// Use a global variable to perform the versioning checks. Swift ensures that
-// the code inside is only computed once.
-private let initializationResult: InitializationResult = {
-    // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 29
-    // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = ffi_emoji_search_uniffi_contract_version()
-    if bindings_contract_version != scaffolding_contract_version {
-        return InitializationResult.contractVersionMismatch
-    }
-    if (uniffi_emoji_search_checksum_func_load_emoji_data() != 10629) {
-        return InitializationResult.apiChecksumMismatch
-    }
-    if (uniffi_emoji_search_checksum_func_search_best_matching_emojis() != 299) {
-        return InitializationResult.apiChecksumMismatch
-    }
-    if (uniffi_emoji_search_checksum_func_search_emojis() != 34429) {
-        return InitializationResult.apiChecksumMismatch
-    }
-
-    uniffiEnsureEmojisInitialized()
-    return InitializationResult.ok
-}()
-
-// Make the ensure init function public so that other modules which have external type references to
-// our types can call it.
-public func uniffiEnsureEmojiSearchInitialized() {
-    switch initializationResult {
-    case .ok:
-        break
-    case .contractVersionMismatch:
-        fatalError("UniFFI contract version mismatch: try cleaning and rebuilding your project")
-    case .apiChecksumMismatch:
-        fatalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-}
-
-// swiftlint:enable all
\ No newline at end of file
@@ -0,0 +1,329 @@
+// src/capi.rs
+//! Plain C ABI for consumers that can't use the UniFFI bindings, e.g. C++ or Go via cgo
+//!
+//! The header `emoji_search.h` is generated from this module by `build.rs` next to the
+//! cdylib and staticlib, e.g. `target/release/emoji_search.h`. `tests/capi.rs` compiles
+//! `tests/c/smoke.c` against it and the staticlib.
+//!
+//! Every call returns an `EmojiSearchStatus`, and on failure the error message can be read
+//! with `emoji_search_last_error_message`.
+
+use crate::constants::{self, EmojiData};
+use crate::custom_emoji::CustomEmoji;
+use crate::error::FfiError;
+use crate::utils::task::block_on;
+use crate::{search_best_matching_emojis, search_best_matching_items, search_emojis, search_items};
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use tracing::error;
+
+thread_local! {
+    /// Message of the last failed call on this thread
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Status of a C API call, mirroring `FfiError`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmojiSearchStatus {
+    Ok = 0,
+    Io = 1,
+    Json = 2,
+    Search = 3,
+    InvalidInput = 4,
+    /// The call panicked, the library state is still usable
+    Panic = 5,
+}
+
+impl From<&FfiError> for EmojiSearchStatus {
+    fn from(err: &FfiError) -> Self {
+        match err {
+            FfiError::Io => EmojiSearchStatus::Io,
+            FfiError::Json => EmojiSearchStatus::Json,
+            FfiError::Search(_) => EmojiSearchStatus::Search,
+            FfiError::InvalidInput(_) => EmojiSearchStatus::InvalidInput,
+        }
+    }
+}
+
+/// An owned array of NUL-terminated UTF-8 strings, freed with `emoji_search_string_array_free`
+#[repr(C)]
+pub struct EmojiSearchStringArray {
+    pub items: *mut *mut c_char,
+    pub len: usize,
+}
+
+impl EmojiSearchStringArray {
+    fn from_emojis<T: Display>(emojis: &[T]) -> Self {
+        let items: Box<[*mut c_char]> = emojis
+            .iter()
+            // Emojis and custom emoji ids read from C strings never contain NUL bytes
+            .filter_map(|emoji| CString::new(emoji.to_string()).ok())
+            .map(CString::into_raw)
+            .collect();
+        let len = items.len();
+
+        Self {
+            items: Box::into_raw(items).cast(),
+            len,
+        }
+    }
+}
+
+/// Load the built-in emoji data into a new handle
+///
+/// # Safety
+/// `out` must be a valid pointer. The handle written to it must be freed with
+/// `emoji_search_data_free`.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_data_load(out: *mut *mut EmojiData) -> EmojiSearchStatus {
+    run(|| {
+        if out.is_null() {
+            return Err(null_pointer_error("out"));
+        }
+
+        let emoji_data = constants::load_emoji_data()?;
+        *out = Box::into_raw(Box::new(emoji_data));
+        Ok(())
+    })
+}
+
+/// Free a handle returned by `emoji_search_data_load`, null is ignored
+///
+/// # Safety
+/// `data` must be null or a handle that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_data_free(data: *mut EmojiData) {
+    if !data.is_null() {
+        drop(Box::from_raw(data));
+    }
+}
+
+/// Search emojis, see `search_emojis`
+///
+/// A `max_limit` of 0 uses the default of 24 results.
+///
+/// # Safety
+/// `data` must be a live handle, `input` a NUL-terminated string and `out` a valid pointer.
+/// The array written to `out` must be freed with `emoji_search_string_array_free`.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_search(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+) -> EmojiSearchStatus {
+    search_into(data, input, max_limit, out, false)
+}
+
+/// Search best matching emojis, see `search_best_matching_emojis`
+///
+/// A `max_limit` of 0 uses the default of 24 results.
+///
+/// # Safety
+/// `data` must be a live handle, `input` a NUL-terminated string and `out` a valid pointer.
+/// The array written to `out` must be freed with `emoji_search_string_array_free`.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_search_best_matching(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+) -> EmojiSearchStatus {
+    search_into(data, input, max_limit, out, true)
+}
+
+/// Search emojis and the custom emojis of the handle, see `search_items`
+///
+/// Custom emojis are returned as their id between colons, e.g. ":partyparrot:". A
+/// `max_limit` of 0 uses the default of 24 results.
+///
+/// # Safety
+/// `data` must be a live handle, `input` a NUL-terminated string and `out` a valid pointer.
+/// The array written to `out` must be freed with `emoji_search_string_array_free`.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_search_items(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+) -> EmojiSearchStatus {
+    search_items_into(data, input, max_limit, out, false)
+}
+
+/// Search best matching emojis and the custom emojis of the handle, see
+/// `search_best_matching_items`
+///
+/// Custom emojis are returned as their id between colons, e.g. ":partyparrot:". A
+/// `max_limit` of 0 uses the default of 24 results.
+///
+/// # Safety
+/// `data` must be a live handle, `input` a NUL-terminated string and `out` a valid pointer.
+/// The array written to `out` must be freed with `emoji_search_string_array_free`.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_search_best_matching_items(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+) -> EmojiSearchStatus {
+    search_items_into(data, input, max_limit, out, true)
+}
+
+/// Add a custom emoji to the handle, replacing the custom emoji with the same id
+///
+/// # Safety
+/// `data` must be a live handle, `id`, `name` and `image` NUL-terminated strings, and
+/// `keywords` null or an array of `num_keywords` NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_data_add_custom_emoji(
+    data: *mut EmojiData,
+    id: *const c_char,
+    name: *const c_char,
+    image: *const c_char,
+    keywords: *const *const c_char,
+    num_keywords: usize,
+) -> EmojiSearchStatus {
+    run(|| {
+        let emoji_data = data.as_mut().ok_or_else(|| null_pointer_error("data"))?;
+        let keywords = if keywords.is_null() {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(keywords, num_keywords)
+                .iter()
+                .map(|&keyword| read_str(keyword, "keyword").map(str::to_string))
+                .collect::<Result<_, _>>()?
+        };
+
+        emoji_data.add_custom_emoji(CustomEmoji {
+            id: read_str(id, "id")?.to_string(),
+            name: read_str(name, "name")?.to_string(),
+            image: read_str(image, "image")?.to_string(),
+            keywords,
+        });
+        Ok(())
+    })
+}
+
+/// Free an array returned by a search and its strings
+///
+/// # Safety
+/// `array` must have been returned by a search and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn emoji_search_string_array_free(array: EmojiSearchStringArray) {
+    if array.items.is_null() {
+        return;
+    }
+
+    let items = Box::from_raw(ptr::slice_from_raw_parts_mut(array.items, array.len));
+    for &item in items.iter() {
+        drop(CString::from_raw(item));
+    }
+}
+
+/// Get the message of the last failed call on this thread, or null if it succeeded
+///
+/// The message is owned by the library and valid until the next call on this thread.
+#[no_mangle]
+pub extern "C" fn emoji_search_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Run a search and write the results to `out`
+unsafe fn search_into(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+    is_best_matching: bool,
+) -> EmojiSearchStatus {
+    run(|| {
+        if out.is_null() {
+            return Err(null_pointer_error("out"));
+        }
+        let emoji_data = data.as_ref().ok_or_else(|| null_pointer_error("data"))?;
+        let input = read_str(input, "input")?;
+        let max_limit = (max_limit > 0).then_some(max_limit);
+
+        let emojis = block_on(async {
+            if is_best_matching {
+                search_best_matching_emojis(input, max_limit, None, emoji_data).await
+            } else {
+                search_emojis(input, max_limit, None, emoji_data).await
+            }
+        })?;
+
+        *out = EmojiSearchStringArray::from_emojis(&emojis);
+        Ok(())
+    })
+}
+
+/// Run a search of emojis and custom emojis and write the results to `out`
+unsafe fn search_items_into(
+    data: *const EmojiData,
+    input: *const c_char,
+    max_limit: u32,
+    out: *mut EmojiSearchStringArray,
+    is_best_matching: bool,
+) -> EmojiSearchStatus {
+    run(|| {
+        if out.is_null() {
+            return Err(null_pointer_error("out"));
+        }
+        let emoji_data = data.as_ref().ok_or_else(|| null_pointer_error("data"))?;
+        let input = read_str(input, "input")?;
+        let max_limit = (max_limit > 0).then_some(max_limit);
+
+        let items = block_on(async {
+            if is_best_matching {
+                search_best_matching_items(input, max_limit, None, emoji_data).await
+            } else {
+                search_items(input, max_limit, None, emoji_data).await
+            }
+        })?;
+
+        *out = EmojiSearchStringArray::from_emojis(&items);
+        Ok(())
+    })
+}
+
+/// Run a call, recording its error message and turning panics into a status
+fn run(f: impl FnOnce() -> Result<(), FfiError>) -> EmojiSearchStatus {
+    let (status, message) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (EmojiSearchStatus::Ok, None),
+        Ok(Err(err)) => (EmojiSearchStatus::from(&err), Some(err.to_string())),
+        Err(_) => (
+            EmojiSearchStatus::Panic,
+            Some("Emoji search panicked".to_string()),
+        ),
+    };
+
+    if let Some(message) = &message {
+        error!("C API call failed: {}", message);
+    }
+
+    LAST_ERROR.with(|last| *last.borrow_mut() = message.and_then(|m| CString::new(m).ok()));
+    status
+}
+
+/// Read a NUL-terminated UTF-8 string argument
+unsafe fn read_str<'a>(s: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if s.is_null() {
+        return Err(null_pointer_error(name));
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| FfiError::InvalidInput(format!("{} is not valid UTF-8", name)))
+}
+
+fn null_pointer_error(name: &str) -> FfiError {
+    FfiError::InvalidInput(format!("{} is null", name))
+}
@@ -0,0 +1,118 @@
+// src/cli/batch.rs
+use crate::cli::format::{to_records, ResultRecord};
+use crate::cli::{search_hits_with_mode, Mode};
+use emoji_search::constants::EmojiData;
+use futures::stream::{self, StreamExt};
+use log::info;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader, Lines};
+
+/// The results of one query of a batch
+#[derive(Debug, Serialize)]
+struct BatchRecord {
+    /// Line number of the query in the input, starting at 1
+    line: usize,
+    query: String,
+    results: Vec<ResultRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Search every line of the input as a query and stream the results as JSONL to stdout
+///
+/// The emoji data is loaded once for the whole batch. Up to `jobs` queries are searched
+/// in parallel on the runtime's worker threads, and results are written in the order of the
+/// input lines.
+pub async fn run_batch(
+    input: Option<&Path>,
+    mode: Mode,
+    limit: u32,
+    jobs: usize,
+    emoji_data: EmojiData,
+) -> io::Result<()> {
+    let reader: Box<dyn AsyncBufRead + Unpin + Send> = match input {
+        Some(path) if path != Path::new("-") => Box::new(BufReader::new(File::open(path).await?)),
+        _ => Box::new(BufReader::new(tokio::io::stdin())),
+    };
+
+    let emoji_data = Arc::new(emoji_data);
+
+    // Each search is spawned so queries run on all worker threads, buffering the handles
+    // keeps the input order
+    let mut records = read_lines(reader.lines())
+        .boxed()
+        .enumerate()
+        .map(|(idx, line)| {
+            let emoji_data = Arc::clone(&emoji_data);
+            tokio::spawn(async move { search_line(idx + 1, line, mode, limit, &emoji_data).await })
+        })
+        .buffered(jobs.max(1));
+
+    let mut stdout = io::stdout().lock();
+    let mut num_queries = 0;
+
+    while let Some(record) = records.next().await {
+        let record = record.map_err(io::Error::other)?;
+        serde_json::to_writer(&mut stdout, &record)?;
+        writeln!(stdout)?;
+        stdout.flush()?;
+        num_queries += 1;
+    }
+
+    info!("Searched {} queries", num_queries);
+    Ok(())
+}
+
+/// Stream the lines of the input, ending after the first read error
+fn read_lines<R: AsyncBufRead + Unpin>(
+    lines: Lines<R>,
+) -> impl futures::Stream<Item = io::Result<String>> {
+    stream::unfold(Some(lines), |lines| async move {
+        let mut lines = lines?;
+        match lines.next_line().await {
+            Ok(Some(line)) => Some((Ok(line), Some(lines))),
+            Ok(None) => None,
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+/// Search one line of the input, recording a read or search error in the record
+async fn search_line(
+    line: usize,
+    query: io::Result<String>,
+    mode: Mode,
+    limit: u32,
+    emoji_data: &EmojiData,
+) -> BatchRecord {
+    let query = match query {
+        Ok(query) => query,
+        Err(e) => {
+            return BatchRecord {
+                line,
+                query: String::new(),
+                results: Vec::new(),
+                error: Some(e.to_string()),
+            }
+        }
+    };
+
+    match search_hits_with_mode(&query, mode, limit, None, emoji_data).await {
+        Ok(hits) => BatchRecord {
+            line,
+            results: to_records(&hits, emoji_data),
+            query,
+            error: None,
+        },
+        Err(e) => BatchRecord {
+            line,
+            query,
+            results: Vec::new(),
+            error: Some(e.to_string()),
+        },
+    }
+}
@@ -0,0 +1,187 @@
+// src/cli/format.rs
+use clap::ValueEnum;
+use emoji_search::{
+    constants::EmojiData,
+    describe::{describe_emoji, EmojiDescription},
+    search::SearchHit,
+};
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Output format of search results
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// One emoji per line
+    #[default]
+    Plain,
+    /// A JSON array of results
+    Json,
+    /// One JSON object per line
+    Jsonl,
+    /// Tab-separated values with a header
+    Tsv,
+    /// Comma-separated values with a header
+    Csv,
+}
+
+/// A search result with everything scripts need to consume it
+#[derive(Debug, Clone, Serialize)]
+pub struct ResultRecord {
+    /// Position in the results, starting at 1
+    pub rank: usize,
+    pub emoji: String,
+    /// CLDR name, e.g. "grinning face"
+    pub name: String,
+    pub group: String,
+    pub shortcodes: Vec<String>,
+    /// The keyword that matched the query, none for emojis the query describes, like flags
+    pub matched_keyword: Option<String>,
+}
+
+/// Column names of the tabular formats
+const HEADER: [&str; 6] = [
+    "rank",
+    "emoji",
+    "name",
+    "group",
+    "shortcodes",
+    "matched_keyword",
+];
+
+/// Column names of a description in the tabular formats
+const DESCRIPTION_HEADER: [&str; 5] = ["emoji", "name", "group", "shortcodes", "keywords"];
+
+/// Build the records of search results
+pub fn to_records(hits: &[SearchHit], emoji_data: &EmojiData) -> Vec<ResultRecord> {
+    hits.iter()
+        .enumerate()
+        .map(|(idx, hit)| {
+            let description = describe_emoji(&hit.emoji, emoji_data);
+            ResultRecord {
+                rank: idx + 1,
+                emoji: hit.emoji.to_string(),
+                name: description.name,
+                group: description.group,
+                shortcodes: description.shortcodes,
+                matched_keyword: hit.matched_keyword.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Write records in the given format
+pub fn write_records(
+    out: &mut impl Write,
+    records: &[ResultRecord],
+    format: Format,
+) -> io::Result<()> {
+    match format {
+        Format::Plain => {
+            for record in records {
+                writeln!(out, "{}", record.emoji)?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)?;
+        }
+        Format::Jsonl => {
+            for record in records {
+                serde_json::to_writer(&mut *out, record)?;
+                writeln!(out)?;
+            }
+        }
+        Format::Tsv => write_delimited(out, records, '\t')?,
+        Format::Csv => write_delimited(out, records, ',')?,
+    }
+
+    Ok(())
+}
+
+/// Write the description of an emoji in the given format
+pub fn write_description(
+    out: &mut impl Write,
+    description: &EmojiDescription,
+    format: Format,
+) -> io::Result<()> {
+    match format {
+        Format::Plain => {
+            writeln!(out, "{} {}", description.emoji, description.name)?;
+            writeln!(out, "group: {}", description.group)?;
+            writeln!(out, "shortcodes: {}", description.shortcodes.join(", "))?;
+            writeln!(out, "keywords: {}", description.keywords.join(", "))?;
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, description)?;
+            writeln!(out)?;
+        }
+        Format::Jsonl => {
+            serde_json::to_writer(&mut *out, description)?;
+            writeln!(out)?;
+        }
+        Format::Tsv | Format::Csv => {
+            let delimiter = if matches!(format, Format::Tsv) {
+                '\t'
+            } else {
+                ','
+            };
+            let header: Vec<String> = DESCRIPTION_HEADER.iter().map(|s| s.to_string()).collect();
+            let row = [
+                description.emoji.to_string(),
+                description.name.clone(),
+                description.group.clone(),
+                description.shortcodes.join(" "),
+                description.keywords.join(", "),
+            ];
+            write_row(out, &header, delimiter)?;
+            write_row(out, &row, delimiter)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write records as delimiter-separated values with a header row
+fn write_delimited(
+    out: &mut impl Write,
+    records: &[ResultRecord],
+    delimiter: char,
+) -> io::Result<()> {
+    let header: Vec<String> = HEADER.iter().map(|s| s.to_string()).collect();
+    write_row(out, &header, delimiter)?;
+
+    for record in records {
+        let row = [
+            record.rank.to_string(),
+            record.emoji.clone(),
+            record.name.clone(),
+            record.group.clone(),
+            record.shortcodes.join(" "),
+            record.matched_keyword.clone().unwrap_or_default(),
+        ];
+        write_row(out, &row, delimiter)?;
+    }
+
+    Ok(())
+}
+
+fn write_row(out: &mut impl Write, fields: &[String], delimiter: char) -> io::Result<()> {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| escape_field(field, delimiter))
+        .collect();
+    writeln!(out, "{}", fields.join(&delimiter.to_string()))
+}
+
+/// Quote CSV fields that need it, and replace tabs and newlines in TSV fields
+fn escape_field(field: &str, delimiter: char) -> String {
+    if delimiter == '\t' {
+        return field.replace(['\t', '\n', '\r'], " ");
+    }
+
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,425 @@
+// src/cli/lsp.rs
+use emoji_search::{constants::EmojiData, describe::describe_emoji, search_emojis};
+use emojis::emoji::Emoji;
+use log::{debug, error};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+
+/// Maximum number of completions offered for a keyword
+const MAX_COMPLETIONS: u32 = 50;
+
+/// Maximum number of code points of an emoji, ZWJ sequences such as families are long
+const MAX_EMOJI_CHARS: usize = 10;
+
+/// Languages in which `:keyword` completes anywhere, in other languages only in comments
+const PROSE_LANGUAGE_IDS: [&str; 6] = [
+    "markdown",
+    "plaintext",
+    "git-commit",
+    "gitcommit",
+    "restructuredtext",
+    "asciidoc",
+];
+
+/// Markers that start a comment in C-like languages, also used for unknown languages
+const C_COMMENT_MARKERS: [&str; 2] = ["//", "/*"];
+
+/// An open text document
+struct Document {
+    language_id: String,
+    text: String,
+}
+
+struct Backend {
+    client: Client,
+    emoji_data: EmojiData,
+    documents: Mutex<HashMap<Url, Document>>,
+}
+
+/// Run a language server on stdin and stdout offering `:keyword` emoji completions
+/// and hover descriptions of emojis
+pub async fn run_language_server(emoji_data: EmojiData) {
+    let (service, socket) = LspService::new(|client| Backend {
+        client,
+        emoji_data,
+        documents: Mutex::new(HashMap::new()),
+    });
+
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+        .serve(service)
+        .await;
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(
+                    TextDocumentSyncKind::FULL,
+                )),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![":".to_string()]),
+                    ..Default::default()
+                }),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "emoji-search".to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        self.client
+            .log_message(MessageType::INFO, "emoji-search language server ready")
+            .await;
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        self.documents.lock().unwrap().insert(
+            document.uri,
+            Document {
+                language_id: document.language_id,
+                text: document.text,
+            },
+        );
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        // With full sync the last change holds the whole text
+        if let Some(change) = params.content_changes.into_iter().last() {
+            if let Some(document) = self
+                .documents
+                .lock()
+                .unwrap()
+                .get_mut(&params.text_document.uri)
+            {
+                document.text = change.text;
+            }
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents
+            .lock()
+            .unwrap()
+            .remove(&params.text_document.uri);
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let position = params.text_document_position.position;
+        let Some((language_id, line)) =
+            self.get_line(&params.text_document_position.text_document.uri, position)
+        else {
+            return Ok(None);
+        };
+
+        let cursor = utf16_to_byte_idx(&line, position.character);
+        let prefix = &line[..cursor];
+
+        let Some(colon_idx) = find_keyword_start(prefix) else {
+            return Ok(None);
+        };
+        if !is_completion_context(&language_id, &prefix[..colon_idx]) {
+            return Ok(None);
+        }
+
+        // Wait for the first keyword character, the client asks again as the user types
+        let keyword = &prefix[colon_idx + 1..];
+        if keyword.is_empty() {
+            return Ok(Some(CompletionResponse::List(CompletionList {
+                is_incomplete: true,
+                items: Vec::new(),
+            })));
+        }
+
+        debug!("Completing emojis for :{}", keyword);
+
+        let query = keyword.replace(['_', '-'], " ");
+        let emojis =
+            match search_emojis(&query, Some(MAX_COMPLETIONS), None, &self.emoji_data).await {
+                Ok(emojis) => emojis,
+                Err(e) => {
+                    error!("Failed to search emojis for {}: {}", keyword, e);
+                    return Ok(None);
+                }
+            };
+
+        let range = Range::new(
+            Position::new(position.line, byte_idx_to_utf16(&line, colon_idx)),
+            position,
+        );
+
+        let items = emojis
+            .iter()
+            .enumerate()
+            .map(|(idx, emoji)| {
+                let description = describe_emoji(emoji, &self.emoji_data);
+                CompletionItem {
+                    label: format!("{} {}", emoji, description.name),
+                    kind: Some(CompletionItemKind::TEXT),
+                    detail: Some(description.name),
+                    documentation: Some(Documentation::String(description.keywords.join(", "))),
+                    // Keep the search ranking, and keep clients from filtering results out
+                    sort_text: Some(format!("{:04}", idx)),
+                    filter_text: Some(format!(":{}", keyword)),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                        range,
+                        emoji.to_string(),
+                    ))),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Ok(Some(CompletionResponse::List(CompletionList {
+            is_incomplete: true,
+            items,
+        })))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params.position;
+        let Some((_, line)) = self.get_line(
+            &params.text_document_position_params.text_document.uri,
+            position,
+        ) else {
+            return Ok(None);
+        };
+
+        let cursor = utf16_to_byte_idx(&line, position.character);
+        let Some((start, end, emoji)) = find_emoji_at(&line, cursor) else {
+            return Ok(None);
+        };
+
+        let description = describe_emoji(&emoji, &self.emoji_data);
+        let mut value = format!("**{} {}**", emoji, description.name);
+        if !description.keywords.is_empty() {
+            value.push_str(&format!("\n\n{}", description.keywords.join(", ")));
+        }
+
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(Range::new(
+                Position::new(position.line, byte_idx_to_utf16(&line, start)),
+                Position::new(position.line, byte_idx_to_utf16(&line, end)),
+            )),
+        }))
+    }
+}
+
+impl Backend {
+    /// Get the language and the text of a line of an open document
+    fn get_line(&self, uri: &Url, position: Position) -> Option<(String, String)> {
+        let documents = self.documents.lock().unwrap();
+        let document = documents.get(uri)?;
+        let line = document.text.lines().nth(position.line as usize)?;
+
+        Some((document.language_id.clone(), line.to_string()))
+    }
+}
+
+/// Find the colon starting the `:keyword` that ends the prefix, e.g. 4 in "hey :smi"
+///
+/// The colon has to start a word, so "http://" and "a:b" don't trigger completions.
+fn find_keyword_start(prefix: &str) -> Option<usize> {
+    let colon_idx =
+        prefix.rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-' || c == '+'))?;
+    if !prefix[colon_idx..].starts_with(':') {
+        return None;
+    }
+
+    let is_word_start = prefix[..colon_idx]
+        .chars()
+        .last()
+        .is_none_or(|c| !(c.is_alphanumeric() || c == ':' || c == '/'));
+
+    if is_word_start {
+        Some(colon_idx)
+    } else {
+        None
+    }
+}
+
+/// Get the markers that start a comment in a language, by its LSP language id
+fn get_comment_markers(language_id: &str) -> &'static [&'static str] {
+    match language_id {
+        "python" | "shellscript" | "ruby" | "perl" | "r" | "yaml" | "toml" | "dockerfile"
+        | "makefile" | "powershell" | "coffeescript" | "elixir" | "julia" | "cmake" => &["#"],
+        "php" => &["//", "/*", "#"],
+        "sql" | "lua" | "haskell" | "elm" | "ada" => &["--"],
+        "clojure" | "lisp" | "scheme" | "racket" | "ini" => &[";"],
+        "html" | "xml" | "vue" | "svelte" => &["<!--"],
+        "css" => &["/*"],
+        "latex" | "tex" | "bibtex" | "erlang" | "matlab" => &["%"],
+        _ => &C_COMMENT_MARKERS,
+    }
+}
+
+/// Check whether a completion makes sense given the text before the `:keyword`
+fn is_completion_context(language_id: &str, before: &str) -> bool {
+    if PROSE_LANGUAGE_IDS.contains(&language_id) {
+        return true;
+    }
+
+    let comment_markers = get_comment_markers(language_id);
+    comment_markers.iter().any(|marker| before.contains(marker))
+        // Continuation lines of block comments
+        || (comment_markers.contains(&"/*") && before.trim_start().starts_with('*'))
+}
+
+/// Find the emoji covering a byte index of the line, with its byte span
+fn find_emoji_at(line: &str, byte_idx: usize) -> Option<(usize, usize, Emoji)> {
+    let char_starts: Vec<usize> = line.char_indices().map(|(idx, _)| idx).collect();
+    let mut i = 0;
+
+    while i < char_starts.len() && char_starts[i] <= byte_idx {
+        let start = char_starts[i];
+
+        // Longest match first, so sequences win over their components
+        let found = (1..=MAX_EMOJI_CHARS.min(char_starts.len() - i))
+            .rev()
+            .find_map(|len| {
+                let end = char_starts.get(i + len).copied().unwrap_or(line.len());
+                emojis::get(&line[start..end]).map(|emoji| (len, end, emoji))
+            });
+
+        match found {
+            Some((_, end, emoji)) if byte_idx < end => return Some((start, end, emoji)),
+            Some((len, _, _)) => i += len,
+            None => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Convert a UTF-16 column, as used in LSP positions, to a byte index of the line
+fn utf16_to_byte_idx(line: &str, utf16_col: u32) -> usize {
+    let mut num_utf16_units = 0;
+
+    for (idx, c) in line.char_indices() {
+        if num_utf16_units >= utf16_col as usize {
+            return idx;
+        }
+        num_utf16_units += c.len_utf16();
+    }
+
+    line.len()
+}
+
+/// Convert a byte index of the line to a UTF-16 column, as used in LSP positions
+fn byte_idx_to_utf16(line: &str, byte_idx: usize) -> u32 {
+    line[..byte_idx].encode_utf16().count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyword_start_at_line_start_and_end() {
+        assert_eq!(find_keyword_start(":smi"), Some(0));
+        assert_eq!(find_keyword_start(":"), Some(0));
+        assert_eq!(find_keyword_start("hey :smi"), Some(4));
+        assert_eq!(find_keyword_start("hey :"), Some(4));
+        assert_eq!(find_keyword_start("thumbs :+1"), Some(7));
+
+        // The keyword has to end the prefix
+        assert_eq!(find_keyword_start("hey :smile "), None);
+        assert_eq!(find_keyword_start("hey :smile:"), None);
+    }
+
+    #[test]
+    fn keyword_start_is_a_byte_index_after_multi_byte_chars() {
+        assert_eq!(find_keyword_start("héllo :dog"), Some(7));
+        assert_eq!(find_keyword_start("🐶 :dog"), Some(5));
+        assert_eq!(find_keyword_start("👨‍👩‍👧 :fam"), Some(19));
+    }
+
+    #[test]
+    fn keyword_start_needs_a_word_start() {
+        assert_eq!(find_keyword_start("http://example"), None);
+        assert_eq!(find_keyword_start("a:b"), None);
+        assert_eq!(find_keyword_start("::dog"), None);
+        assert_eq!(find_keyword_start("é:dog"), None);
+    }
+
+    fn emoji_at(line: &str, byte_idx: usize) -> Option<(usize, usize, String)> {
+        find_emoji_at(line, byte_idx).map(|(start, end, emoji)| (start, end, emoji.to_string()))
+    }
+
+    #[test]
+    fn emoji_at_covers_the_whole_sequence() {
+        // 👍 and 🏽 are 4 bytes each
+        let line = "a 👍🏽 b";
+        let thumbs_up = Some((2, 10, "👍🏽".to_string()));
+        assert_eq!(emoji_at(line, 2), thumbs_up);
+        assert_eq!(emoji_at(line, 6), thumbs_up);
+        assert_eq!(emoji_at(line, 9), thumbs_up);
+        assert_eq!(emoji_at(line, 0), None);
+        assert_eq!(emoji_at(line, 10), None);
+
+        let family = "👨‍👩‍👧";
+        assert_eq!(
+            emoji_at(family, family.len() - 1),
+            Some((0, family.len(), family.to_string()))
+        );
+    }
+
+    #[test]
+    fn emoji_at_line_start_and_end() {
+        assert_eq!(emoji_at("🐶 dog", 0), Some((0, 4, "🐶".to_string())));
+        assert_eq!(emoji_at("dog 🐶", 4), Some((4, 8, "🐶".to_string())));
+        // The cursor after the last character is outside of it
+        assert_eq!(emoji_at("dog 🐶", 8), None);
+        assert_eq!(emoji_at("", 0), None);
+    }
+
+    #[test]
+    fn utf16_columns_and_byte_indices() {
+        // 🐶 is 2 UTF-16 units and 4 bytes, é is 1 unit and 2 bytes
+        let line = "a🐶é b";
+        for (utf16_col, byte_idx) in [(0, 0), (1, 1), (3, 5), (4, 7), (5, 8), (6, 9)] {
+            assert_eq!(
+                utf16_to_byte_idx(line, utf16_col),
+                byte_idx,
+                "{}",
+                utf16_col
+            );
+            assert_eq!(byte_idx_to_utf16(line, byte_idx), utf16_col, "{}", byte_idx);
+        }
+
+        // Inside a surrogate pair rounds to the next character, past the end to the end
+        assert_eq!(utf16_to_byte_idx(line, 2), 5);
+        assert_eq!(utf16_to_byte_idx(line, 100), line.len());
+        assert_eq!(utf16_to_byte_idx("", 0), 0);
+    }
+
+    #[test]
+    fn utf16_round_trip_at_every_char_boundary() {
+        let line = ":dog 👨‍👩‍👧 héllo 🐶";
+        for (byte_idx, _) in line.char_indices() {
+            let utf16_col = byte_idx_to_utf16(line, byte_idx);
+            assert_eq!(utf16_to_byte_idx(line, utf16_col), byte_idx);
+        }
+        assert_eq!(
+            byte_idx_to_utf16(line, line.len()),
+            line.encode_utf16().count() as u32
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// src/cli/mod.rs
+pub mod batch;
+pub mod format;
+pub mod lsp;
+pub mod picker;
+pub mod serve;
+
+use clap::ValueEnum;
+use emoji_search::{
+    constants::{EmojiData, Options},
+    error::FfiResult,
+    search::SearchHit,
+    search_best_matching_hits, search_hits, suggest_for_message,
+};
+use emojis::emoji::Emoji;
+
+/// How a query is matched
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Mode {
+    /// Narrow results as more words are typed
+    Standard,
+    /// Also match stemmed words
+    Best,
+    /// Best emoji for every concept of a message
+    Message,
+}
+
+/// Search emojis for a query with the given mode
+pub async fn search_with_mode(
+    query: &str,
+    mode: Mode,
+    max_limit: u32,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<Emoji>> {
+    let hits = search_hits_with_mode(query, mode, max_limit, options, emoji_data).await?;
+    Ok(hits.into_iter().map(|hit| hit.emoji).collect())
+}
+
+/// Search emojis for a query with the given mode, with the keyword each emoji matched
+///
+/// In message mode, the matched keyword is the concept of the message the emoji was
+/// suggested for.
+pub async fn search_hits_with_mode(
+    query: &str,
+    mode: Mode,
+    max_limit: u32,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<SearchHit>> {
+    match mode {
+        Mode::Standard => search_hits(query, Some(max_limit), options, emoji_data).await,
+        Mode::Best => search_best_matching_hits(query, Some(max_limit), options, emoji_data).await,
+        Mode::Message => {
+            let suggestions =
+                suggest_for_message(query, Some(max_limit), options, emoji_data).await?;
+            let hits = suggestions
+                .top
+                .into_iter()
+                .map(|emoji| {
+                    let matched_keyword = suggestions
+                        .concepts
+                        .iter()
+                        .find(|concept| concept.suggestions.iter().any(|s| s.emoji == emoji))
+                        .map(|concept| concept.phrase.clone());
+                    SearchHit {
+                        emoji,
+                        matched_keyword,
+                    }
+                })
+                .collect();
+            Ok(hits)
+        }
+    }
+}
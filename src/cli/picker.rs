@@ -0,0 +1,248 @@
+// src/cli/picker.rs
+use crossterm::{
+    cursor::{MoveTo, Show},
+    event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use emoji_search::{
+    constants::EmojiData,
+    describe::{describe_emoji, EmojiDescription},
+    error::FfiResult,
+    search_emojis,
+};
+use emojis::emoji::Emoji;
+use futures::StreamExt;
+use log::{debug, warn};
+use std::io::{self, Stderr, Write};
+use std::process::{Command, Stdio};
+use unicode_width::UnicodeWidthStr;
+
+/// Commands copying their stdin to the clipboard, tried in order
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("pbcopy", &[])];
+#[cfg(target_os = "windows")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("clip", &[])];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Puts the terminal in raw mode on an alternate screen, and restores it when dropped
+/// so that errors and panics don't leave the terminal broken
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stderr(), EnterAlternateScreen)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stderr(), LeaveAlternateScreen, Show);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Let the user pick an emoji interactively, searching again on every keystroke
+///
+/// The picker is drawn on stderr so that stdout stays free for the chosen emoji, which is
+/// also copied to the clipboard. Returns `None` when the user cancels with Esc or Ctrl-C.
+pub async fn pick_emoji(
+    initial_query: &str,
+    limit: u32,
+    emoji_data: &EmojiData,
+) -> Result<Option<Emoji>, Box<dyn std::error::Error>> {
+    let guard = TerminalGuard::enter()?;
+    let mut stderr = io::stderr();
+
+    let mut query = initial_query.to_string();
+    let mut results = search(&query, limit, emoji_data).await?;
+    let mut selected = 0;
+    let mut events = EventStream::new();
+
+    loop {
+        render(&mut stderr, &query, &results, selected)?;
+
+        let Some(event) = events.next().await.transpose()? else {
+            return Ok(None);
+        };
+        let Event::Key(key) = event else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let is_ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let mut is_query_changed = false;
+
+        match key.code {
+            KeyCode::Enter => {
+                let emoji = results.get(selected).map(|d| d.emoji.clone());
+                // Restore the terminal first, so a warning isn't drawn over the picker
+                drop(guard);
+                if let Some(emoji) = &emoji {
+                    if let Err(err) = copy_to_clipboard(emoji.as_str()) {
+                        warn!("Failed to copy {} to the clipboard: {}", emoji, err);
+                    }
+                }
+                return Ok(emoji);
+            }
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if is_ctrl => return Ok(None),
+            KeyCode::Up => selected = selected.saturating_sub(1),
+            KeyCode::Char('p') if is_ctrl => selected = selected.saturating_sub(1),
+            KeyCode::Down => selected = (selected + 1).min(results.len().saturating_sub(1)),
+            KeyCode::Char('n') if is_ctrl => {
+                selected = (selected + 1).min(results.len().saturating_sub(1))
+            }
+            KeyCode::Char('u') if is_ctrl => {
+                query.clear();
+                is_query_changed = true;
+            }
+            KeyCode::Backspace => is_query_changed = query.pop().is_some(),
+            KeyCode::Char(c) if !is_ctrl => {
+                query.push(c);
+                is_query_changed = true;
+            }
+            _ => {}
+        }
+
+        if is_query_changed {
+            results = search(&query, limit, emoji_data).await?;
+            selected = 0;
+        }
+    }
+}
+
+/// Search emojis for the query and describe each result
+async fn search(
+    query: &str,
+    limit: u32,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<EmojiDescription>> {
+    let emojis = search_emojis(query, Some(limit), None, emoji_data).await?;
+
+    Ok(emojis
+        .iter()
+        .map(|emoji| describe_emoji(emoji, emoji_data))
+        .collect())
+}
+
+/// Draw the query line, the result count and the visible results
+fn render(
+    out: &mut Stderr,
+    query: &str,
+    results: &[EmojiDescription],
+    selected: usize,
+) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let width = width as usize;
+    let num_visible_results = (height as usize).saturating_sub(2);
+
+    // Scroll so that the selected result stays visible
+    let offset = (selected + 1).saturating_sub(num_visible_results);
+
+    queue!(
+        out,
+        Clear(ClearType::All),
+        MoveTo(0, 1),
+        Print(format!("  {} results", results.len()))
+    )?;
+
+    for (row, (idx, description)) in results
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(num_visible_results)
+        .enumerate()
+    {
+        // The emoji name is usually the first keyword already
+        let keywords: Vec<&str> = description
+            .keywords
+            .iter()
+            .map(String::as_str)
+            .filter(|keyword| *keyword != description.name)
+            .collect();
+        let line = format!(
+            "{}  {}  {}",
+            description.emoji,
+            description.name,
+            keywords.join(", ")
+        );
+        let line = truncate_to_width(&line, width.saturating_sub(3));
+
+        queue!(out, MoveTo(0, row as u16 + 2))?;
+        if idx == selected {
+            queue!(
+                out,
+                SetAttribute(Attribute::Reverse),
+                Print(format!("> {line}")),
+                SetAttribute(Attribute::Reset)
+            )?;
+        } else {
+            queue!(out, Print(format!("  {line}")))?;
+        }
+    }
+
+    // Leave the cursor at the end of the query
+    queue!(out, MoveTo(0, 0), Print(format!("> {query}")))?;
+    out.flush()
+}
+
+/// Truncate a line to the number of terminal columns it may take, emojis take two
+fn truncate_to_width(line: &str, max_width: usize) -> &str {
+    if line.width() <= max_width {
+        return line;
+    }
+
+    let end = line
+        .char_indices()
+        .map(|(idx, c)| idx + c.len_utf8())
+        .take_while(|&end| line[..end].width() <= max_width)
+        .last()
+        .unwrap_or(0);
+    &line[..end]
+}
+
+/// Copy the text to the clipboard with the first clipboard command that is installed
+fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        let mut child = match Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        let status = child.wait()?;
+        if status.success() {
+            debug!("Copied {} to the clipboard with {}", text, program);
+            return Ok(());
+        }
+        return Err(io::Error::other(format!(
+            "{} exited with {}",
+            program, status
+        )));
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "no clipboard command found",
+    ))
+}
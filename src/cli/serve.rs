@@ -0,0 +1,182 @@
+// src/cli/serve.rs
+use crate::cli::format::{to_records, ResultRecord};
+use crate::cli::{search_hits_with_mode, Mode};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use emoji_search::{
+    constants::{EmojiData, Options},
+    describe,
+    describe::EmojiDescription,
+    error::FfiError,
+};
+use log::info;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+
+/// Default maximum number of results of a search
+const DEFAULT_LIMIT: u32 = 24;
+
+#[derive(Serialize)]
+struct SearchResponse {
+    query: String,
+    results: Vec<ResultRecord>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// An error turned into a JSON response, invalid requests are reported as bad requests
+struct ApiError(FfiError);
+
+impl From<FfiError> for ApiError {
+    fn from(err: FfiError) -> Self {
+        ApiError(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self.0 {
+            FfiError::InvalidInput(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (
+            status,
+            Json(ErrorResponse {
+                error: self.0.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
+
+/// Serve emoji search over a local HTTP/JSON API
+///
+/// Endpoints:
+/// - `GET /search?q=dog&limit=10` searches emojis
+/// - `GET /best?q=running+dogs` searches best matching emojis
+/// - `GET /describe?emoji=🐶` describes an emoji
+///
+/// Searches accept `recent=dog,cat` for recently searched inputs, `keyword=🐶:pupper,doggo`
+/// for custom emoji keywords and `most_relevant=pupper:🐶` for custom most relevant emojis.
+/// Each of them can be repeated.
+pub async fn run_server(addr: SocketAddr, emoji_data: EmojiData) -> io::Result<()> {
+    let app = Router::new()
+        .route("/search", get(search))
+        .route("/best", get(search_best_matching))
+        .route("/describe", get(describe_emoji))
+        .with_state(emoji_data);
+
+    let listener = TcpListener::bind(addr).await?;
+    info!("Listening on http://{}", listener.local_addr()?);
+
+    axum::serve(listener, app).await
+}
+
+async fn search(
+    State(emoji_data): State<EmojiData>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<Json<SearchResponse>, ApiError> {
+    search_with_params(&params, Mode::Standard, &emoji_data).await
+}
+
+async fn search_best_matching(
+    State(emoji_data): State<EmojiData>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<Json<SearchResponse>, ApiError> {
+    search_with_params(&params, Mode::Best, &emoji_data).await
+}
+
+async fn describe_emoji(
+    State(emoji_data): State<EmojiData>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<EmojiDescription>, ApiError> {
+    let emoji = params
+        .get("emoji")
+        .ok_or_else(|| FfiError::InvalidInput("missing emoji parameter".to_string()))?;
+
+    Ok(Json(describe(emoji, &emoji_data)?))
+}
+
+async fn search_with_params(
+    params: &[(String, String)],
+    mode: Mode,
+    emoji_data: &EmojiData,
+) -> Result<Json<SearchResponse>, ApiError> {
+    let query = get_param(params, "q")
+        .ok_or_else(|| FfiError::InvalidInput("missing q parameter".to_string()))?
+        .to_string();
+    let limit = match get_param(params, "limit") {
+        Some(limit) => limit
+            .parse()
+            .map_err(|_| FfiError::InvalidInput(format!("invalid limit: {}", limit)))?,
+        None => DEFAULT_LIMIT,
+    };
+    let options = options_from_params(params)?;
+
+    let hits = search_hits_with_mode(&query, mode, limit, Some(options), emoji_data).await?;
+    let results = to_records(&hits, emoji_data);
+
+    Ok(Json(SearchResponse { query, results }))
+}
+
+fn get_param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Collect the `recent`, `keyword` and `most_relevant` parameters into search options
+fn options_from_params(params: &[(String, String)]) -> Result<Options, FfiError> {
+    let mut recently_searched_inputs: Option<Vec<String>> = None;
+    let mut custom_emoji_keywords: Option<HashMap<String, Vec<String>>> = None;
+    let mut custom_keyword_most_relevant_emoji: Option<HashMap<String, String>> = None;
+
+    for (key, value) in params {
+        match key.as_str() {
+            "recent" => recently_searched_inputs
+                .get_or_insert_with(Vec::new)
+                .extend(value.split(',').map(|s| s.trim().to_string())),
+            "keyword" => {
+                let (emoji, keywords) = split_pair(value, "keyword")?;
+                custom_emoji_keywords
+                    .get_or_insert_with(HashMap::new)
+                    .entry(emoji.trim().to_string())
+                    .or_default()
+                    .extend(keywords.split(',').map(|s| s.trim().to_string()));
+            }
+            "most_relevant" => {
+                let (keyword, emoji) = split_pair(value, "most_relevant")?;
+                custom_keyword_most_relevant_emoji
+                    .get_or_insert_with(HashMap::new)
+                    .insert(keyword.trim().to_string(), emoji.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    Options::from_emoji_strings(
+        custom_emoji_keywords,
+        custom_keyword_most_relevant_emoji,
+        recently_searched_inputs,
+    )
+    .map_err(FfiError::from)
+}
+
+fn split_pair<'a>(value: &'a str, name: &str) -> Result<(&'a str, &'a str), FfiError> {
+    value.split_once(':').ok_or_else(|| {
+        FfiError::InvalidInput(format!("{} must look like a:b, got {}", name, value))
+    })
+}
@@ -1,11 +1,18 @@
 // src/constants.rs
+use crate::custom_emoji::{CustomEmoji, CustomEmojiIndex};
 use crate::error::{EmojiSearchError, Result};
+use crate::utils::preprocess::pre_process_string;
 use emojis::common::EMOJIS;
 use emojis::emoji::Emoji;
 use emojis::get;
+#[cfg(not(target_arch = "wasm32"))]
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Map from emoji to its keywords
 pub type EmojiKeywords = HashMap<Emoji, Vec<String>>;
@@ -19,21 +26,87 @@ pub type EmojiGlossary = HashMap<String, Vec<Emoji>>;
 /// Map of words to their index in top 1000 words
 pub type WordToTop1000WordsIdx = HashMap<String, usize>;
 
+/// Map from pre-processed multiple words keyword to the emojis that have it
+pub type PhraseEmojis = HashMap<String, Vec<Emoji>>;
+
+/// Embedded JSON map from emoji to its keywords
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const EMBEDDED_EMOJI_KEYWORDS: &str =
+    include_str!("../data/emoogle-emoji-keywords.json");
+
+/// Embedded JSON map from keyword to most relevant emoji
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const EMBEDDED_KEYWORD_MOST_RELEVANT_EMOJI: &str =
+    include_str!("../data/emoogle-keyword-most-relevant-emoji.json");
+
+/// Embedded JSON map from keyword to emojis
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const EMBEDDED_EMOJI_GLOSSARY: &str =
+    include_str!("../data/emoogle-emoji-glossary.json");
+
+/// Embedded JSON list of words by frequency
+#[cfg(not(target_arch = "wasm32"))]
+const EMBEDDED_TOP_1000_WORDS: &str = include_str!("../data/top-1000-words-by-frequency.json");
+
 /// Options for customizing emoji search
 #[derive(Clone, Default)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
 pub struct Options {
     /// Custom emoji keywords to extend built-in keywords
+    #[cfg_attr(not(target_arch = "wasm32"), uniffi(default = None))]
     pub custom_emoji_keywords: Option<EmojiKeywords>,
 
     /// Custom mappings from keywords to preferred emojis
+    #[cfg_attr(not(target_arch = "wasm32"), uniffi(default = None))]
     pub custom_keyword_most_relevant_emoji: Option<KeywordMostRelevantEmoji>,
 
     /// Recently searched inputs for improved search suggestions
+    #[cfg_attr(not(target_arch = "wasm32"), uniffi(default = None))]
     pub recently_searched_inputs: Option<Vec<String>>,
 }
 
+impl Options {
+    /// Build options from maps with emojis given as strings, emojis or shortcodes, like the
+    /// bindings and the CLI receive them
+    ///
+    /// # Returns
+    /// The options, or an invalid input error if an emoji isn't recognized
+    pub fn from_emoji_strings(
+        custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+        custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+        recently_searched_inputs: Option<Vec<String>>,
+    ) -> Result<Self> {
+        let custom_emoji_keywords = custom_emoji_keywords
+            .map(|emoji_keywords| {
+                emoji_keywords
+                    .into_iter()
+                    .map(|(emoji, keywords)| Ok((parse_emoji(&emoji)?, keywords)))
+                    .collect::<Result<EmojiKeywords>>()
+            })
+            .transpose()?;
+
+        let custom_keyword_most_relevant_emoji = custom_keyword_most_relevant_emoji
+            .map(|keyword_emoji| {
+                keyword_emoji
+                    .into_iter()
+                    .map(|(keyword, emoji)| Ok((keyword, parse_emoji(&emoji)?)))
+                    .collect::<Result<KeywordMostRelevantEmoji>>()
+            })
+            .transpose()?;
+
+        Ok(Options {
+            custom_emoji_keywords,
+            custom_keyword_most_relevant_emoji,
+            recently_searched_inputs,
+        })
+    }
+}
+
 /// Core data structure containing all emoji data
+///
+/// Exposed through FFI as an opaque object, its fields stay on the Rust side.
 #[derive(Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Object))]
 pub struct EmojiData {
     /// Map from emoji to its keywords
     /// e.g. {"➕": ["plus", "add", "sum", "and", "increase", "positive", "math"]}
@@ -52,6 +125,20 @@ pub struct EmojiData {
 
     /// Map of words to their frequency rank in top 1000 words
     pub word_to_top_1000_words_idx: Arc<WordToTop1000WordsIdx>,
+
+    /// Map from multiple words keyword to the emojis that have it, derived from
+    /// `emoji_keywords` to find phrases inside longer inputs
+    /// e.g. {"red heart": ["❤️"]}
+    pub phrase_emojis: Arc<PhraseEmojis>,
+
+    /// Custom emojis that aren't Unicode, only returned by `search_items`
+    pub custom_emojis: Arc<CustomEmojiIndex>,
+}
+
+impl Default for EmojiData {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EmojiData {
@@ -62,6 +149,8 @@ impl EmojiData {
         let emoji_glossary = Arc::new(HashMap::new());
         let emoji_set = Arc::new(HashSet::new());
         let word_to_top_1000_words_idx = Arc::new(HashMap::new());
+        let phrase_emojis = Arc::new(HashMap::new());
+        let custom_emojis = Arc::new(CustomEmojiIndex::default());
 
         Self {
             emoji_keywords,
@@ -69,19 +158,255 @@ impl EmojiData {
             emoji_glossary,
             emoji_set,
             word_to_top_1000_words_idx,
+            phrase_emojis,
+            custom_emojis,
+        }
+    }
+
+    /// Add keywords to an emoji, adding the emoji if it has none yet
+    ///
+    /// The maps are copied on write, so clones of this data taken before are unaffected.
+    /// Keywords the emoji already has are skipped. An emoji without keywords first gets
+    /// its CLDR name, as the first keyword is matched as the emoji name. The curated
+    /// `emoji_glossary` is left unchanged.
+    pub fn add_keywords(&mut self, emoji: &Emoji, keywords: &[String]) {
+        let emoji_keywords = Arc::make_mut(&mut self.emoji_keywords)
+            .entry(emoji.clone())
+            .or_default();
+
+        let name = emoji_keywords
+            .is_empty()
+            .then(|| normalize_keyword(emoji.name()));
+        let keywords: Vec<String> = name
+            .into_iter()
+            .chain(keywords.iter().map(|keyword| normalize_keyword(keyword)))
+            .filter(|keyword| !keyword.is_empty())
+            .collect();
+        debug!("Adding keywords to {}: {:?}", emoji, keywords);
+
+        for keyword in keywords {
+            if emoji_keywords.contains(&keyword) {
+                continue;
+            }
+
+            if let Some(phrase) = to_phrase(&keyword) {
+                let phrase_emojis = Arc::make_mut(&mut self.phrase_emojis)
+                    .entry(phrase)
+                    .or_default();
+                if !phrase_emojis.contains(emoji) {
+                    phrase_emojis.push(emoji.clone());
+                }
+            }
+
+            emoji_keywords.push(keyword);
+        }
+
+        if !self.emoji_set.contains(emoji) {
+            Arc::make_mut(&mut self.emoji_set).insert(emoji.clone());
+        }
+    }
+
+    /// Remove a keyword from an emoji, the curated `emoji_glossary` is left unchanged
+    ///
+    /// # Returns
+    /// Whether the emoji had the keyword
+    pub fn remove_keyword(&mut self, emoji: &Emoji, keyword: &str) -> bool {
+        let keyword = normalize_keyword(keyword);
+
+        let has_keyword = self
+            .emoji_keywords
+            .get(emoji)
+            .is_some_and(|keywords| keywords.contains(&keyword));
+        if !has_keyword {
+            return false;
+        }
+        debug!("Removing keyword {} from {}", keyword, emoji);
+
+        if let Some(keywords) = Arc::make_mut(&mut self.emoji_keywords).get_mut(emoji) {
+            keywords.retain(|k| k != &keyword);
+        }
+
+        if let Some(phrase) = to_phrase(&keyword) {
+            let phrase_emojis = Arc::make_mut(&mut self.phrase_emojis);
+            if let Some(emojis) = phrase_emojis.get_mut(&phrase) {
+                emojis.retain(|e| e != emoji);
+                if emojis.is_empty() {
+                    phrase_emojis.remove(&phrase);
+                }
+            }
+        }
+
+        // The emoji can't be the most relevant for a keyword it doesn't have
+        if self.keyword_most_relevant_emoji.get(&keyword) == Some(emoji) {
+            Arc::make_mut(&mut self.keyword_most_relevant_emoji).remove(&keyword);
+        }
+
+        true
+    }
+
+    /// Make an emoji the most relevant one for a keyword, adding the keyword to the emoji
+    /// if it doesn't have it, as only keywords of an emoji are matched
+    pub fn set_most_relevant(&mut self, keyword: &str, emoji: &Emoji) {
+        let keyword = normalize_keyword(keyword);
+        debug!("Setting most relevant emoji for {} to {}", keyword, emoji);
+
+        self.add_keywords(emoji, std::slice::from_ref(&keyword));
+        Arc::make_mut(&mut self.keyword_most_relevant_emoji).insert(keyword, emoji.clone());
+    }
+
+    /// Remove an emoji with all its keywords, so searches no longer return it
+    ///
+    /// The curated `emoji_glossary` is left unchanged, emojify only suggests its emojis
+    /// that are in `emoji_set`.
+    ///
+    /// # Returns
+    /// Whether the emoji was known
+    pub fn remove_emoji(&mut self, emoji: &Emoji) -> bool {
+        if !self.emoji_set.contains(emoji) && !self.emoji_keywords.contains_key(emoji) {
+            return false;
+        }
+        debug!("Removing emoji {}", emoji);
+
+        Arc::make_mut(&mut self.emoji_keywords).remove(emoji);
+        Arc::make_mut(&mut self.emoji_set).remove(emoji);
+
+        Arc::make_mut(&mut self.keyword_most_relevant_emoji).retain(|_, e| e != emoji);
+
+        Arc::make_mut(&mut self.phrase_emojis).retain(|_, phrase_emojis| {
+            phrase_emojis.retain(|e| e != emoji);
+            !phrase_emojis.is_empty()
+        });
+
+        true
+    }
+
+    /// Add a custom emoji, replacing the custom emoji with the same id if there is one
+    pub fn add_custom_emoji(&mut self, custom_emoji: CustomEmoji) {
+        self.add_custom_emojis([custom_emoji]);
+    }
+
+    /// Add custom emojis, replacing the custom emojis with the same ids, and index them once
+    pub fn add_custom_emojis(&mut self, custom_emojis: impl IntoIterator<Item = CustomEmoji>) {
+        let custom_emojis = custom_emojis.into_iter().map(|mut custom_emoji| {
+            custom_emoji.keywords = custom_emoji
+                .keywords
+                .iter()
+                .map(|keyword| normalize_keyword(keyword))
+                .filter(|keyword| !keyword.is_empty())
+                .collect();
+            debug!("Adding custom emoji {}", custom_emoji);
+            custom_emoji
+        });
+
+        Arc::make_mut(&mut self.custom_emojis).extend(custom_emojis);
+    }
+
+    /// Remove a custom emoji by its id
+    ///
+    /// # Returns
+    /// Whether there was a custom emoji with the id
+    pub fn remove_custom_emoji(&mut self, id: &str) -> bool {
+        if self.custom_emojis.get(id).is_none() {
+            return false;
+        }
+        debug!("Removing custom emoji :{}:", id);
+
+        Arc::make_mut(&mut self.custom_emojis).remove(id)
+    }
+}
+
+/// Normalize a keyword like the ones in the data files, e.g. " Happy " -> "happy"
+pub(crate) fn normalize_keyword(keyword: &str) -> String {
+    keyword.trim().to_lowercase()
+}
+
+/// Pre-process a keyword as searches match it, if it has multiple words
+fn to_phrase(keyword: &str) -> Option<String> {
+    let phrase = pre_process_string(keyword).trim().to_string();
+    phrase.contains(' ').then_some(phrase)
+}
+
+/// Index the multiple words keywords of all emojis, emojis of a phrase in emoji order
+pub fn build_phrase_emojis(emoji_keywords: &EmojiKeywords) -> PhraseEmojis {
+    let mut phrase_emojis: PhraseEmojis = HashMap::new();
+
+    for (emoji, keywords) in emoji_keywords {
+        for phrase in keywords.iter().filter_map(|keyword| to_phrase(keyword)) {
+            let emojis = phrase_emojis.entry(phrase).or_default();
+            if !emojis.contains(emoji) {
+                emojis.push(emoji.clone());
+            }
+        }
+    }
+
+    for emojis in phrase_emojis.values_mut() {
+        emojis.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    }
+
+    phrase_emojis
+}
+
+/// The data files with emojis as strings, as bundled in the snapshot generated by `build.rs`
+///
+/// The field order must match `EmojiDataSnapshot` in `build.rs`.
+#[cfg(target_arch = "wasm32")]
+#[derive(serde::Deserialize)]
+struct EmojiDataSnapshot {
+    emoji_keywords: HashMap<String, Vec<String>>,
+    keyword_most_relevant_emoji: HashMap<String, String>,
+    emoji_glossary: HashMap<String, Vec<String>>,
+    top_1000_words: Vec<String>,
+}
+
+/// Paths of data files to load instead of the embedded ones, `None` keeps the embedded file
+#[derive(Debug, Clone, Default)]
+pub struct EmojiDataPaths {
+    /// JSON map from emoji to its keywords, like `data/emoogle-emoji-keywords.json`
+    pub emoji_keywords: Option<PathBuf>,
+    /// JSON map from keyword to emoji, like `data/emoogle-keyword-most-relevant-emoji.json`
+    pub keyword_most_relevant_emoji: Option<PathBuf>,
+    /// JSON map from keyword to emojis, like `data/emoogle-emoji-glossary.json`
+    pub emoji_glossary: Option<PathBuf>,
+    /// JSON list of words by frequency, like `data/top-1000-words-by-frequency.json`
+    pub top_1000_words: Option<PathBuf>,
+}
+
+impl EmojiDataPaths {
+    /// Use the data files of a data pack, a directory with files named like the ones in
+    /// `data/`, files missing from the directory keep the embedded file
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_dir(dir: &Path) -> Self {
+        let existing = |file_name: &str| {
+            let path = dir.join(file_name);
+            path.is_file().then_some(path)
+        };
+
+        Self {
+            emoji_keywords: existing("emoogle-emoji-keywords.json"),
+            keyword_most_relevant_emoji: existing("emoogle-keyword-most-relevant-emoji.json"),
+            emoji_glossary: existing("emoogle-emoji-glossary.json"),
+            top_1000_words: existing("top-1000-words-by-frequency.json"),
         }
     }
 }
 
 /// Load emoji data from embedded JSON files
+#[cfg(not(target_arch = "wasm32"))]
 pub fn load_emoji_data() -> Result<EmojiData> {
-    info!("Loading emoji data from embedded resources");
+    load_emoji_data_from_paths(&EmojiDataPaths::default())
+}
+
+/// Load emoji data from JSON files, using the embedded file for every path that isn't given
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_emoji_data_from_paths(paths: &EmojiDataPaths) -> Result<EmojiData> {
+    info!("Loading emoji data from {:?}", paths);
 
     // First, parse the JSON into a temporary HashMap with String keys
     let emoji_json_data: HashMap<String, Vec<String>> =
-        match serde_json::from_str::<HashMap<String, Vec<String>>>(include_str!(
-            "../data/emoogle-emoji-keywords.json"
-        )) {
+        match serde_json::from_str::<HashMap<String, Vec<String>>>(&read_data_file(
+            paths.emoji_keywords.as_deref(),
+            EMBEDDED_EMOJI_KEYWORDS,
+        )?) {
             Ok(data) => {
                 info!("Loaded emoji keywords JSON: {} entries", data.len());
                 data
@@ -92,32 +417,152 @@ pub fn load_emoji_data() -> Result<EmojiData> {
             }
         };
 
-    // Then convert the HashMap with String keys to one with &'static Emoji keys
+    let keyword_most_relevant_emoji: HashMap<String, String> =
+        serde_json::from_str(&read_data_file(
+            paths.keyword_most_relevant_emoji.as_deref(),
+            EMBEDDED_KEYWORD_MOST_RELEVANT_EMOJI,
+        )?)?;
+
+    let emoji_glossary: HashMap<String, Vec<String>> = serde_json::from_str(&read_data_file(
+        paths.emoji_glossary.as_deref(),
+        EMBEDDED_EMOJI_GLOSSARY,
+    )?)?;
+
+    let top_1000_words: Vec<String> = serde_json::from_str(&read_data_file(
+        paths.top_1000_words.as_deref(),
+        EMBEDDED_TOP_1000_WORDS,
+    )?)?;
+
+    Ok(build_emoji_data(
+        parse_emoji_keywords(emoji_json_data),
+        parse_keyword_most_relevant_emoji(keyword_most_relevant_emoji),
+        parse_emoji_glossary(emoji_glossary),
+        &top_1000_words,
+    ))
+}
+
+/// Read a data file, or use the embedded contents when no path is given
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn read_data_file(
+    path: Option<&Path>,
+    embedded: &'static str,
+) -> Result<Cow<'static, str>> {
+    match path {
+        Some(path) => {
+            info!("Reading data file {}", path.display());
+            std::fs::read_to_string(path).map(Cow::Owned).map_err(|e| {
+                error!("Failed to read {}: {}", path.display(), e);
+                EmojiSearchError::Io(e)
+            })
+        }
+        None => Ok(Cow::Borrowed(embedded)),
+    }
+}
+
+/// Load emoji data from the bitcode snapshot embedded by `build.rs`
+///
+/// The snapshot is a fraction of the size of the JSON files, which matters for the wasm
+/// bundle, and decodes without a JSON parser.
+#[cfg(target_arch = "wasm32")]
+pub fn load_emoji_data() -> Result<EmojiData> {
+    info!("Loading emoji data from embedded snapshot");
+
+    let snapshot: EmojiDataSnapshot = bitcode::deserialize(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/emoji-data.bitcode"
+    )))
+    .map_err(|e| {
+        error!("Failed to decode emoji data snapshot: {}", e);
+        EmojiSearchError::Search(format!("Failed to decode emoji data snapshot: {}", e))
+    })?;
+
+    Ok(build_emoji_data(
+        parse_emoji_keywords(snapshot.emoji_keywords),
+        parse_keyword_most_relevant_emoji(snapshot.keyword_most_relevant_emoji),
+        parse_emoji_glossary(snapshot.emoji_glossary),
+        &snapshot.top_1000_words,
+    ))
+}
+
+/// Parse an emoji or a shortcode, e.g. "🐶" or "dog"
+///
+/// # Returns
+/// The emoji, or an invalid input error if it isn't recognized
+pub fn parse_emoji(s: &str) -> Result<Emoji> {
+    let s = s.trim();
+    emojis::get(s)
+        .or_else(|| emojis::get_by_shortcode(s).map(|emoji| emoji.to_owned()))
+        .ok_or_else(|| {
+            EmojiSearchError::InvalidInput(format!("{} is not a recognized emoji or shortcode", s))
+        })
+}
+
+/// Convert the emoji keywords with String keys to ones with Emoji keys, skipping unknown
+/// emojis
+pub(crate) fn parse_emoji_keywords(emoji_json_data: HashMap<String, Vec<String>>) -> EmojiKeywords {
     let mut emoji_keywords: EmojiKeywords = HashMap::new();
     for (emoji_str, keywords) in emoji_json_data {
-        // Assuming the keys in your JSON are emoji characters
-        if let Some(emoji) = emojis::get(&emoji_str) {
-            emoji_keywords.insert(emoji, keywords);
-        } else {
-            // If the keys are shortcodes instead, try this
-            if let Some(emoji) = emojis::get_by_shortcode(&emoji_str) {
-                emoji_keywords.insert(emoji.to_owned(), keywords);
-            } else {
-                warn!("Could not find emoji for key: {}", emoji_str);
+        match parse_emoji(&emoji_str) {
+            Ok(emoji) => {
+                emoji_keywords.insert(emoji, keywords);
             }
+            Err(_) => warn!("Could not find emoji for key: {}", emoji_str),
         }
     }
 
-    let keyword_most_relevant_emoji: KeywordMostRelevantEmoji = serde_json::from_str(
-        include_str!("../data/emoogle-keyword-most-relevant-emoji.json"),
-    )?;
+    emoji_keywords
+}
 
-    let emoji_glossary: EmojiGlossary =
-        serde_json::from_str(include_str!("../data/emoogle-emoji-glossary.json"))?;
+/// Convert the most relevant emojis with String values to Emoji values, skipping unknown
+/// emojis
+pub(crate) fn parse_keyword_most_relevant_emoji(
+    json_data: HashMap<String, String>,
+) -> KeywordMostRelevantEmoji {
+    json_data
+        .into_iter()
+        .filter_map(|(keyword, emoji_str)| match parse_emoji(&emoji_str) {
+            Ok(emoji) => Some((keyword, emoji)),
+            Err(_) => {
+                warn!(
+                    "Could not find emoji {} for keyword: {}",
+                    emoji_str, keyword
+                );
+                None
+            }
+        })
+        .collect()
+}
 
-    let top_1000_words: Vec<String> =
-        serde_json::from_str(include_str!("../data/top-1000-words-by-frequency.json"))?;
+/// Convert the glossary with String emojis to Emoji ones, skipping unknown emojis
+pub(crate) fn parse_emoji_glossary(json_data: HashMap<String, Vec<String>>) -> EmojiGlossary {
+    json_data
+        .into_iter()
+        .map(|(keyword, emoji_strs)| {
+            let emojis = emoji_strs
+                .iter()
+                .filter_map(|emoji_str| {
+                    let emoji = parse_emoji(emoji_str).ok();
+                    if emoji.is_none() {
+                        warn!(
+                            "Could not find emoji {} for keyword: {}",
+                            emoji_str, keyword
+                        );
+                    }
+                    emoji
+                })
+                .collect();
+            (keyword, emojis)
+        })
+        .collect()
+}
 
+/// Assemble the emoji data from the parsed data files
+fn build_emoji_data(
+    emoji_keywords: EmojiKeywords,
+    keyword_most_relevant_emoji: KeywordMostRelevantEmoji,
+    emoji_glossary: EmojiGlossary,
+    top_1000_words: &[String],
+) -> EmojiData {
     // Create emoji set from keys of emoji_keywords
     let emoji_set: HashSet<Emoji> = EMOJIS.iter().cloned().collect();
 
@@ -132,13 +577,17 @@ pub fn load_emoji_data() -> Result<EmojiData> {
         })
         .collect();
 
+    let phrase_emojis = build_phrase_emojis(&emoji_keywords);
+
     info!("Emoji data loaded successfully");
 
-    Ok(EmojiData {
+    EmojiData {
         emoji_keywords: Arc::new(emoji_keywords),
         keyword_most_relevant_emoji: Arc::new(keyword_most_relevant_emoji),
         emoji_glossary: Arc::new(emoji_glossary),
         emoji_set: Arc::new(emoji_set),
         word_to_top_1000_words_idx: Arc::new(word_to_top_1000_words_idx),
-    })
+        phrase_emojis: Arc::new(phrase_emojis),
+        custom_emojis: Arc::new(CustomEmojiIndex::default()),
+    }
 }
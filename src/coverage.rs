@@ -0,0 +1,202 @@
+// src/coverage.rs
+//! Keyword coverage of the emoji set, and fallback keywords for emojis search can't reach
+//!
+//! An emoji is only returned by a search if it has keywords, so emojis missing from the
+//! keywords file are unreachable even though they are in `emoji_set`.
+
+use crate::constants::EmojiData;
+use crate::utils::get_emojis::{group_name, GROUPS};
+use emojis::emoji::Emoji;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::{debug, info, warn};
+
+/// Emojis with fewer keywords than this are reported as thin by default
+pub const DEFAULT_MIN_KEYWORDS: u32 = 3;
+
+/// An emoji with missing or thin keywords
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EmojiCoverage {
+    pub emoji: String,
+    pub name: String,
+    pub group: String,
+    /// e.g. "15.0"
+    pub unicode_version: String,
+    pub num_keywords: u32,
+}
+
+/// Coverage counts of a group or Unicode version
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct CoverageBreakdown {
+    /// The group name, e.g. "Smileys & Emotion", or the Unicode version, e.g. "15.0"
+    pub label: String,
+    pub num_emojis: u32,
+    /// Emojis without keywords
+    pub num_missing: u32,
+    /// Emojis with fewer keywords than the minimum
+    pub num_thin: u32,
+}
+
+/// Keyword coverage of all emojis in the emoji set
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct CoverageReport {
+    pub num_emojis: u32,
+    pub num_missing: u32,
+    pub num_thin: u32,
+    pub min_keywords: u32,
+    /// In Unicode group order
+    pub by_group: Vec<CoverageBreakdown>,
+    /// In Unicode version order
+    pub by_unicode_version: Vec<CoverageBreakdown>,
+    /// Emojis without keywords, unreachable by search
+    pub missing: Vec<EmojiCoverage>,
+    /// Emojis with fewer keywords than the minimum
+    pub thin: Vec<EmojiCoverage>,
+}
+
+/// Report the emojis of the emoji set with missing or thin keyword lists
+///
+/// # Arguments
+/// * `min_keywords` - Emojis with fewer keywords are reported as thin
+/// * `emoji_data` - The emoji data to check
+///
+/// # Returns
+/// The coverage report, with counts by group and Unicode version
+pub fn compute_coverage(min_keywords: u32, emoji_data: &EmojiData) -> CoverageReport {
+    let mut emojis: Vec<&Emoji> = emoji_data.emoji_set.iter().collect();
+    emojis.sort_by_key(|emoji| emoji.to_string());
+
+    let mut by_group: Vec<CoverageBreakdown> = GROUPS
+        .iter()
+        .map(|&group| new_breakdown(group_name(group)))
+        .collect();
+    let mut by_unicode_version: BTreeMap<(u32, u32), CoverageBreakdown> = BTreeMap::new();
+    let mut missing = Vec::new();
+    let mut thin = Vec::new();
+
+    for emoji in &emojis {
+        let num_keywords = emoji_data
+            .emoji_keywords
+            .get(*emoji)
+            .map_or(0, |keywords| keywords.len() as u32);
+
+        let unicode_version = emoji.unicode_version();
+        let version_key = (unicode_version.major(), unicode_version.minor());
+        let version_label = format!("{}.{}", version_key.0, version_key.1);
+
+        let group_idx = GROUPS.iter().position(|&group| group == emoji.group());
+        if group_idx.is_none() {
+            warn!(
+                "{} is in the unlisted group {:?}, leaving it out of the group counts",
+                emoji,
+                emoji.group()
+            );
+        }
+
+        let group_breakdown = group_idx.map(|idx| &mut by_group[idx]);
+        let version_breakdown = by_unicode_version
+            .entry(version_key)
+            .or_insert_with(|| new_breakdown(&version_label));
+        for breakdown in group_breakdown.into_iter().chain([version_breakdown]) {
+            breakdown.num_emojis += 1;
+            if num_keywords == 0 {
+                breakdown.num_missing += 1;
+            } else if num_keywords < min_keywords {
+                breakdown.num_thin += 1;
+            }
+        }
+
+        if num_keywords < min_keywords {
+            let emoji_coverage = EmojiCoverage {
+                emoji: emoji.to_string(),
+                name: emoji.name().to_string(),
+                group: group_name(emoji.group()).to_string(),
+                unicode_version: version_label,
+                num_keywords,
+            };
+
+            if num_keywords == 0 {
+                missing.push(emoji_coverage);
+            } else {
+                thin.push(emoji_coverage);
+            }
+        }
+    }
+
+    info!(
+        "Keyword coverage: {} emojis, {} missing, {} thin",
+        emojis.len(),
+        missing.len(),
+        thin.len()
+    );
+
+    CoverageReport {
+        num_emojis: emojis.len() as u32,
+        num_missing: missing.len() as u32,
+        num_thin: thin.len() as u32,
+        min_keywords,
+        by_group,
+        by_unicode_version: by_unicode_version.into_values().collect(),
+        missing,
+        thin,
+    }
+}
+
+/// Generate keywords for an emoji from its CLDR name and shortcodes
+///
+/// The name comes first, as the first keyword is matched as the emoji name,
+/// e.g. 😀 -> ["grinning face", "grinning"] for the shortcodes "grinning_face" and "grinning".
+pub fn fallback_keywords(emoji: &Emoji) -> Vec<String> {
+    let mut keywords = vec![emoji.name().to_lowercase()];
+
+    for shortcode in emoji.shortcodes() {
+        let keyword = shortcode.replace('_', " ").to_lowercase();
+        if !keywords.contains(&keyword) {
+            keywords.push(keyword);
+        }
+    }
+
+    keywords
+}
+
+/// Give every emoji without keywords the fallback keywords, so no emoji is unsearchable
+///
+/// The curated glossary is left unchanged, the fallback keywords are matched like the
+/// keywords from the keywords file.
+///
+/// # Returns
+/// The number of emojis that got fallback keywords
+pub fn add_fallback_keywords(emoji_data: &mut EmojiData) -> u32 {
+    let missing: Vec<Emoji> = emoji_data
+        .emoji_set
+        .iter()
+        .filter(|emoji| {
+            emoji_data
+                .emoji_keywords
+                .get(*emoji)
+                .is_none_or(|keywords| keywords.is_empty())
+        })
+        .cloned()
+        .collect();
+
+    for emoji in &missing {
+        let keywords = fallback_keywords(emoji);
+        debug!("Adding fallback keywords to {}: {:?}", emoji, keywords);
+        emoji_data.add_keywords(emoji, &keywords);
+    }
+
+    info!("Added fallback keywords to {} emojis", missing.len());
+    missing.len() as u32
+}
+
+fn new_breakdown(label: &str) -> CoverageBreakdown {
+    CoverageBreakdown {
+        label: label.to_string(),
+        num_emojis: 0,
+        num_missing: 0,
+        num_thin: 0,
+    }
+}
@@ -0,0 +1,184 @@
+// src/custom_emoji.rs
+//! Custom emojis that aren't Unicode, e.g. workspace emojis like ":partyparrot:"
+//!
+//! Custom emojis are added to `EmojiData` and searched with `search_items` and
+//! `search_best_matching_items`, where they are ranked together with the Unicode emojis by
+//! the same rules.
+
+use crate::utils::preprocess::pre_process_string;
+use emojis::emoji::Emoji;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Bound;
+
+/// A custom emoji, identified by its id instead of a Unicode sequence
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct CustomEmoji {
+    /// Unique id, typed between colons, e.g. "partyparrot"
+    pub id: String,
+
+    /// Display name, matched like the name of a Unicode emoji, e.g. "party parrot"
+    pub name: String,
+
+    /// URL or path of the image
+    pub image: String,
+
+    /// Keywords, matched like the keywords of a Unicode emoji
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl CustomEmoji {
+    /// The keywords to match against, the name first like for Unicode emojis, then the id
+    fn searchable_keywords(&self) -> Vec<String> {
+        let mut keywords = Vec::with_capacity(self.keywords.len() + 2);
+        keywords.push(self.name.clone());
+        if self.id != self.name {
+            keywords.push(self.id.clone());
+        }
+        keywords.extend(self.keywords.iter().cloned());
+        keywords
+    }
+}
+
+impl fmt::Display for CustomEmoji {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ":{}:", self.id)
+    }
+}
+
+/// Custom emojis with their searchable keywords, indexed by the words of the keywords
+///
+/// Built when custom emojis are added to the emoji data, so searches only match the custom
+/// emojis having a word that starts with an input word.
+#[derive(Debug, Clone, Default)]
+pub struct CustomEmojiIndex {
+    /// Custom emojis with their searchable keywords, in the order they were added
+    entries: Vec<(CustomEmoji, Vec<String>)>,
+    /// Map from every pre-processed keyword word to the indices of the entries having it
+    /// e.g. {"parrot": [0], "party": [0, 2]}
+    words: BTreeMap<String, Vec<usize>>,
+}
+
+impl CustomEmojiIndex {
+    /// Iterate over the custom emojis, in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = &CustomEmoji> {
+        self.entries.iter().map(|(custom_emoji, _)| custom_emoji)
+    }
+
+    /// Get a custom emoji by its id
+    pub fn get(&self, id: &str) -> Option<&CustomEmoji> {
+        self.iter().find(|custom_emoji| custom_emoji.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add custom emojis, replacing the ones with the same ids, and rebuild the word index
+    pub(crate) fn extend(&mut self, custom_emojis: impl IntoIterator<Item = CustomEmoji>) {
+        for custom_emoji in custom_emojis {
+            let keywords = custom_emoji.searchable_keywords();
+            match self
+                .entries
+                .iter_mut()
+                .find(|(e, _)| e.id == custom_emoji.id)
+            {
+                Some(existing) => *existing = (custom_emoji, keywords),
+                None => self.entries.push((custom_emoji, keywords)),
+            }
+        }
+
+        self.rebuild_words();
+    }
+
+    /// Remove a custom emoji by its id, returning whether there was one
+    pub(crate) fn remove(&mut self, id: &str) -> bool {
+        let len = self.entries.len();
+        self.entries
+            .retain(|(custom_emoji, _)| custom_emoji.id != id);
+        if self.entries.len() == len {
+            return false;
+        }
+
+        self.rebuild_words();
+        true
+    }
+
+    /// Get the custom emojis with a keyword word starting with one of the input words, with
+    /// their searchable keywords, in the order they were added
+    pub(crate) fn candidates<'a>(
+        &'a self,
+        input_words: &[&str],
+    ) -> Vec<(&'a CustomEmoji, &'a [String])> {
+        let mut indices: Vec<usize> = input_words
+            .iter()
+            .filter(|word| !word.is_empty())
+            .flat_map(|&word| {
+                self.words
+                    .range::<str, _>((Bound::Included(word), Bound::Unbounded))
+                    .take_while(move |(keyword_word, _)| keyword_word.starts_with(word))
+                    .flat_map(|(_, indices)| indices.iter().copied())
+            })
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .map(|idx| {
+                let (custom_emoji, keywords) = &self.entries[idx];
+                (custom_emoji, keywords.as_slice())
+            })
+            .collect()
+    }
+
+    fn rebuild_words(&mut self) {
+        self.words.clear();
+        for (idx, (_, keywords)) in self.entries.iter().enumerate() {
+            for keyword in keywords {
+                for word in pre_process_string(keyword).split(' ') {
+                    let indices = self.words.entry(word.to_string()).or_default();
+                    if indices.last() != Some(&idx) {
+                        indices.push(idx);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A search result, either a Unicode emoji or a custom emoji
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Enum))]
+pub enum SearchResult {
+    Unicode { emoji: Emoji },
+    Custom { emoji: CustomEmoji },
+}
+
+impl From<Emoji> for SearchResult {
+    fn from(emoji: Emoji) -> Self {
+        SearchResult::Unicode { emoji }
+    }
+}
+
+impl From<CustomEmoji> for SearchResult {
+    fn from(emoji: CustomEmoji) -> Self {
+        SearchResult::Custom { emoji }
+    }
+}
+
+impl fmt::Display for SearchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchResult::Unicode { emoji } => emoji.fmt(f),
+            SearchResult::Custom { emoji } => emoji.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// src/describe.rs
+use crate::constants::EmojiData;
+use crate::utils::get_emojis::group_name;
+use emojis::emoji::Emoji;
+use serde::Serialize;
+use tracing::trace;
+
+/// Everything known about an emoji, e.g. for showing it beside search results
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EmojiDescription {
+    pub emoji: Emoji,
+    /// CLDR name, e.g. "grinning face"
+    pub name: String,
+    /// Display name of the emoji group, e.g. "Smileys & Emotion"
+    pub group: String,
+    /// Shortcodes without colons, e.g. ["grinning"]
+    pub shortcodes: Vec<String>,
+    /// Search keywords, e.g. ["grinning face", "happy", "smile"]
+    pub keywords: Vec<String>,
+}
+
+/// Describe an emoji using its Unicode data and its search keywords
+pub fn describe_emoji(emoji: &Emoji, emoji_data: &EmojiData) -> EmojiDescription {
+    trace!("Describing emoji {}", emoji);
+
+    EmojiDescription {
+        emoji: emoji.clone(),
+        name: emoji.name().to_string(),
+        group: group_name(emoji.group()).to_string(),
+        shortcodes: emoji.shortcodes().map(|s| s.to_string()).collect(),
+        keywords: emoji_data
+            .emoji_keywords
+            .get(emoji)
+            .cloned()
+            .unwrap_or_default(),
+    }
+}
@@ -10,6 +10,9 @@ pub enum EmojiSearchError {
     /// JSON parsing errors
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
+    /// CBOR parsing errors
+    #[error("CBOR parsing error: {0}")]
+    Cbor(#[from] serde_cbor::Error),
     /// Search-related errors
     #[error("Search error: {0}")]
     Search(String),
@@ -21,6 +24,7 @@ pub enum EmojiSearchError {
 /// Error type exposed through FFI
 /// This implements all the necessary UniFFI traits
 #[derive(Debug, thiserror::Error)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Error))]
 pub enum FfiError {
     #[error("IO error")]
     Io,
@@ -37,7 +41,8 @@ impl From<EmojiSearchError> for FfiError {
     fn from(err: EmojiSearchError) -> Self {
         match err {
             EmojiSearchError::Io(_) => FfiError::Io,
-            EmojiSearchError::Json(_) => FfiError::Json,
+            // Data files are parsing errors across FFI, whatever their format
+            EmojiSearchError::Json(_) | EmojiSearchError::Cbor(_) => FfiError::Json,
             EmojiSearchError::Search(msg) => FfiError::Search(msg),
             EmojiSearchError::InvalidInput(msg) => FfiError::InvalidInput(msg),
         }
@@ -0,0 +1,496 @@
+// src/evaluate.rs
+//! Offline relevance evaluation of search results against judged queries
+//!
+//! Judgments are a JSON map from query to its relevant emojis, most relevant first, e.g.
+//! `{"dog": ["🐶", "🐕"], "red heart": ["❤️"]}`. Any search, a mode or a data pack, is
+//! evaluated by running it on every query, and two evaluations can be diffed to see which
+//! queries got better or worse after a ranking change.
+
+use crate::error::{EmojiSearchError, FfiResult, Result};
+use emojis::emoji::Emoji;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use tracing::{debug, info};
+
+/// Metrics with changes smaller than this are considered unchanged
+const EPSILON: f64 = 1e-9;
+
+/// A judged query
+#[derive(Debug, Clone)]
+pub struct Judgment {
+    pub query: String,
+    /// Relevant emojis, most relevant first
+    pub relevant: Vec<Emoji>,
+}
+
+/// Relevance metrics at a cutoff k, of a query or averaged over all queries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct Metrics {
+    /// Mean reciprocal rank of the first relevant result, the reciprocal rank for a query
+    pub mrr: f64,
+    /// Normalized discounted cumulative gain, with gains graded by the judged order
+    pub ndcg: f64,
+    pub precision: f64,
+    pub recall: f64,
+}
+
+impl Metrics {
+    fn sub(&self, other: &Metrics) -> Metrics {
+        Metrics {
+            mrr: self.mrr - other.mrr,
+            ndcg: self.ndcg - other.ndcg,
+            precision: self.precision - other.precision,
+            recall: self.recall - other.recall,
+        }
+    }
+}
+
+/// Evaluation of a single query
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct QueryEvaluation {
+    pub query: String,
+    /// The top k results
+    pub results: Vec<String>,
+    pub metrics: Metrics,
+}
+
+/// Evaluation of a search over all judged queries
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EvaluationReport {
+    pub k: u32,
+    pub num_queries: u32,
+    /// Metrics averaged over all queries
+    pub metrics: Metrics,
+    pub queries: Vec<QueryEvaluation>,
+}
+
+/// A query whose results changed between two evaluations
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct QueryDiff {
+    pub query: String,
+    pub baseline: Metrics,
+    pub candidate: Metrics,
+    pub baseline_results: Vec<String>,
+    pub candidate_results: Vec<String>,
+}
+
+/// Differences between a baseline and a candidate evaluation of the same judgments
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EvaluationDiff {
+    pub k: u32,
+    pub baseline: Metrics,
+    pub candidate: Metrics,
+    /// Candidate minus baseline, positive is better
+    pub delta: Metrics,
+    /// Queries with a higher nDCG in the candidate, biggest change first
+    pub improved: Vec<QueryDiff>,
+    /// Queries with a lower nDCG in the candidate, biggest change first
+    pub regressed: Vec<QueryDiff>,
+    /// Number of queries whose results changed without changing the nDCG
+    pub num_reordered: u32,
+}
+
+/// Parse judgments from a JSON map from query to its relevant emojis, most relevant first
+///
+/// # Returns
+/// The judgments sorted by query, or an error if an emoji isn't recognized
+pub fn parse_judgments(json: &str) -> Result<Vec<Judgment>> {
+    let judgments: BTreeMap<String, Vec<String>> = serde_json::from_str(json)?;
+
+    judgments
+        .into_iter()
+        .map(|(query, emoji_strs)| {
+            let relevant = emoji_strs
+                .iter()
+                .map(|emoji_str| {
+                    emojis::get(emoji_str).ok_or_else(|| {
+                        EmojiSearchError::InvalidInput(format!(
+                            "{} is not a recognized emoji, judged for \"{}\"",
+                            emoji_str, query
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<Emoji>>>()?;
+
+            Ok(Judgment { query, relevant })
+        })
+        .collect()
+}
+
+/// Run a search on every judged query and compute its metrics at k
+///
+/// # Arguments
+/// * `judgments` - The judged queries
+/// * `k` - The cutoff, only the top k results of each query are evaluated
+/// * `search` - The search to evaluate, called with a query and k
+///
+/// # Returns
+/// The metrics of every query and their averages
+pub async fn evaluate<F, Fut>(
+    judgments: &[Judgment],
+    k: u32,
+    search: F,
+) -> FfiResult<EvaluationReport>
+where
+    F: Fn(String, u32) -> Fut,
+    Fut: Future<Output = FfiResult<Vec<Emoji>>>,
+{
+    info!("Evaluating {} judged queries at k = {}", judgments.len(), k);
+
+    let mut queries = Vec::with_capacity(judgments.len());
+    let mut total = Metrics::default();
+
+    for judgment in judgments {
+        let results: Vec<Emoji> = search(judgment.query.clone(), k)
+            .await?
+            .into_iter()
+            .take(k as usize)
+            .collect();
+        let metrics = compute_metrics(&results, &judgment.relevant, k as usize);
+        debug!("Evaluated \"{}\": {:?}", judgment.query, metrics);
+
+        total.mrr += metrics.mrr;
+        total.ndcg += metrics.ndcg;
+        total.precision += metrics.precision;
+        total.recall += metrics.recall;
+
+        queries.push(QueryEvaluation {
+            query: judgment.query.clone(),
+            results: results.iter().map(|emoji| emoji.to_string()).collect(),
+            metrics,
+        });
+    }
+
+    let num_queries = queries.len().max(1) as f64;
+    let metrics = Metrics {
+        mrr: total.mrr / num_queries,
+        ndcg: total.ndcg / num_queries,
+        precision: total.precision / num_queries,
+        recall: total.recall / num_queries,
+    };
+    info!("Evaluation metrics: {:?}", metrics);
+
+    Ok(EvaluationReport {
+        k,
+        num_queries: queries.len() as u32,
+        metrics,
+        queries,
+    })
+}
+
+/// Compare a candidate evaluation, e.g. of another mode or data pack, with a baseline
+///
+/// Queries are matched by their text, queries only in one of the evaluations are skipped.
+pub fn diff_evaluations(
+    baseline: &EvaluationReport,
+    candidate: &EvaluationReport,
+) -> EvaluationDiff {
+    let candidate_queries: BTreeMap<&str, &QueryEvaluation> = candidate
+        .queries
+        .iter()
+        .map(|query| (query.query.as_str(), query))
+        .collect();
+
+    let mut improved = Vec::new();
+    let mut regressed = Vec::new();
+    let mut num_reordered = 0;
+
+    for baseline_query in &baseline.queries {
+        let Some(candidate_query) = candidate_queries.get(baseline_query.query.as_str()) else {
+            continue;
+        };
+        if baseline_query.results == candidate_query.results {
+            continue;
+        }
+
+        let query_diff = QueryDiff {
+            query: baseline_query.query.clone(),
+            baseline: baseline_query.metrics,
+            candidate: candidate_query.metrics,
+            baseline_results: baseline_query.results.clone(),
+            candidate_results: candidate_query.results.clone(),
+        };
+
+        let ndcg_delta = candidate_query.metrics.ndcg - baseline_query.metrics.ndcg;
+        if ndcg_delta > EPSILON {
+            improved.push(query_diff);
+        } else if ndcg_delta < -EPSILON {
+            regressed.push(query_diff);
+        } else {
+            num_reordered += 1;
+        }
+    }
+
+    let ndcg_change =
+        |query_diff: &QueryDiff| (query_diff.candidate.ndcg - query_diff.baseline.ndcg).abs();
+    improved.sort_by(|a, b| ndcg_change(b).total_cmp(&ndcg_change(a)));
+    regressed.sort_by(|a, b| ndcg_change(b).total_cmp(&ndcg_change(a)));
+
+    EvaluationDiff {
+        k: candidate.k,
+        baseline: baseline.metrics,
+        candidate: candidate.metrics,
+        delta: candidate.metrics.sub(&baseline.metrics),
+        improved,
+        regressed,
+        num_reordered,
+    }
+}
+
+/// Compute the metrics of the top k results of a query
+fn compute_metrics(results: &[Emoji], relevant: &[Emoji], k: usize) -> Metrics {
+    if relevant.is_empty() || k == 0 {
+        return Metrics::default();
+    }
+
+    let top_results = &results[..results.len().min(k)];
+
+    let mrr = top_results
+        .iter()
+        .position(|emoji| relevant.contains(emoji))
+        .map_or(0.0, |rank| 1.0 / (rank + 1) as f64);
+
+    let num_relevant_results = top_results
+        .iter()
+        .filter(|emoji| relevant.contains(emoji))
+        .count() as f64;
+
+    // The first judged emoji has the highest gain, the last one a gain of 1
+    let gain = |emoji: &Emoji| {
+        relevant
+            .iter()
+            .position(|e| e == emoji)
+            .map_or(0.0, |idx| (relevant.len() - idx) as f64)
+    };
+    let discount = |rank: usize| 1.0 / (rank as f64 + 2.0).log2();
+
+    let dcg: f64 = top_results
+        .iter()
+        .enumerate()
+        .map(|(rank, emoji)| gain(emoji) * discount(rank))
+        .sum();
+    let ideal_dcg: f64 = relevant
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(rank, emoji)| gain(emoji) * discount(rank))
+        .sum();
+
+    Metrics {
+        mrr,
+        ndcg: dcg / ideal_dcg,
+        precision: num_relevant_results / k as f64,
+        recall: num_relevant_results / relevant.len() as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn emojis(strs: &[&str]) -> Vec<Emoji> {
+        strs.iter()
+            .map(|s| emojis::get(s).unwrap_or_else(|| panic!("{} is not an emoji", s)))
+            .collect()
+    }
+
+    fn assert_metrics(actual: Metrics, expected: Metrics) {
+        for (name, actual, expected) in [
+            ("mrr", actual.mrr, expected.mrr),
+            ("ndcg", actual.ndcg, expected.ndcg),
+            ("precision", actual.precision, expected.precision),
+            ("recall", actual.recall, expected.recall),
+        ] {
+            assert!(
+                (actual - expected).abs() < TOLERANCE,
+                "{}: {} != {}",
+                name,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn metrics_with_graded_gains() {
+        // Gains 3, 2 and 1 for 🐶, 🐕 and 🐱, found at ranks 2 and 3
+        let metrics = compute_metrics(
+            &emojis(&["🍕", "🐶", "🐱"]),
+            &emojis(&["🐶", "🐕", "🐱"]),
+            3,
+        );
+
+        let dcg = 3.0 / 3f64.log2() + 1.0 / 4f64.log2();
+        let ideal_dcg = 3.0 + 2.0 / 3f64.log2() + 1.0 / 4f64.log2();
+        assert_metrics(
+            metrics,
+            Metrics {
+                mrr: 0.5,
+                ndcg: dcg / ideal_dcg,
+                precision: 2.0 / 3.0,
+                recall: 2.0 / 3.0,
+            },
+        );
+    }
+
+    #[test]
+    fn metrics_of_a_perfect_ranking() {
+        let relevant = emojis(&["🐶", "🐕"]);
+        let metrics = compute_metrics(&relevant, &relevant, 2);
+
+        assert_metrics(
+            metrics,
+            Metrics {
+                mrr: 1.0,
+                ndcg: 1.0,
+                precision: 1.0,
+                recall: 1.0,
+            },
+        );
+    }
+
+    #[test]
+    fn metrics_with_k_greater_than_the_number_of_results() {
+        // Precision is over k even when fewer results are returned
+        let metrics = compute_metrics(&emojis(&["🐕"]), &emojis(&["🐶", "🐕"]), 5);
+
+        let ideal_dcg = 2.0 + 1.0 / 3f64.log2();
+        assert_metrics(
+            metrics,
+            Metrics {
+                mrr: 1.0,
+                ndcg: 1.0 / ideal_dcg,
+                precision: 1.0 / 5.0,
+                recall: 1.0 / 2.0,
+            },
+        );
+    }
+
+    #[test]
+    fn metrics_with_k_smaller_than_the_number_of_relevant_emojis() {
+        // The ideal ranking is cut off at k too
+        let metrics = compute_metrics(&emojis(&["🐶", "🐕"]), &emojis(&["🐶", "🐕", "🐱"]), 1);
+
+        assert_metrics(
+            metrics,
+            Metrics {
+                mrr: 1.0,
+                ndcg: 1.0,
+                precision: 1.0,
+                recall: 1.0 / 3.0,
+            },
+        );
+    }
+
+    #[test]
+    fn metrics_of_an_empty_result_list() {
+        let metrics = compute_metrics(&[], &emojis(&["🐶"]), 3);
+        assert_metrics(metrics, Metrics::default());
+
+        // Nothing to find without judged emojis or a cutoff
+        let metrics = compute_metrics(&emojis(&["🐶"]), &[], 3);
+        assert_metrics(metrics, Metrics::default());
+        let metrics = compute_metrics(&emojis(&["🐶"]), &emojis(&["🐶"]), 0);
+        assert_metrics(metrics, Metrics::default());
+    }
+
+    #[tokio::test]
+    async fn evaluate_averages_over_queries_and_cuts_off_at_k() {
+        let judgments = parse_judgments(r#"{"dog": ["🐶"], "pizza": ["🍕"]}"#).unwrap();
+
+        let report = evaluate(&judgments, 2, |query, _k| async move {
+            // More results than k, and no relevant result for "pizza"
+            let results = match query.as_str() {
+                "dog" => emojis(&["🐕", "🐶", "🐱"]),
+                _ => emojis(&["🍔", "🍟", "🍕"]),
+            };
+            Ok(results)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(report.num_queries, 2);
+        assert_eq!(report.queries[0].query, "dog");
+        assert_eq!(report.queries[0].results, ["🐕", "🐶"]);
+        assert_metrics(
+            report.metrics,
+            Metrics {
+                mrr: 0.5 / 2.0,
+                ndcg: (1.0 / 3f64.log2()) / 2.0,
+                precision: 0.5 / 2.0,
+                recall: 1.0 / 2.0,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_judgments_rejects_unknown_emojis() {
+        assert!(parse_judgments(r#"{"dog": ["not an emoji"]}"#).is_err());
+    }
+
+    fn query_evaluation(query: &str, results: &[&str], ndcg: f64) -> QueryEvaluation {
+        QueryEvaluation {
+            query: query.to_string(),
+            results: results.iter().map(|s| s.to_string()).collect(),
+            metrics: Metrics {
+                ndcg,
+                ..Metrics::default()
+            },
+        }
+    }
+
+    fn report(queries: Vec<QueryEvaluation>) -> EvaluationReport {
+        let ndcg = queries.iter().map(|query| query.metrics.ndcg).sum::<f64>() / 4.0;
+        EvaluationReport {
+            k: 10,
+            num_queries: queries.len() as u32,
+            metrics: Metrics {
+                ndcg,
+                ..Metrics::default()
+            },
+            queries,
+        }
+    }
+
+    #[test]
+    fn diff_evaluations_splits_improved_regressed_and_reordered() {
+        let baseline = report(vec![
+            query_evaluation("a", &["🐶"], 0.5),
+            query_evaluation("b", &["🐱"], 1.0),
+            query_evaluation("c", &["🍕", "🍔"], 0.5),
+            query_evaluation("d", &["🎉"], 0.25),
+            query_evaluation("e", &["🌮"], 0.9),
+        ]);
+        let candidate = report(vec![
+            query_evaluation("a", &["🐕"], 1.0),
+            query_evaluation("b", &["🐈"], 0.2),
+            // Same nDCG, different results
+            query_evaluation("c", &["🍔", "🍕"], 0.5),
+            // Unchanged
+            query_evaluation("d", &["🎉"], 0.25),
+            query_evaluation("e", &["🌯"], 1.0),
+            // Only in the candidate
+            query_evaluation("f", &["🌭"], 1.0),
+        ]);
+
+        let diff = diff_evaluations(&baseline, &candidate);
+
+        let improved: Vec<&str> = diff.improved.iter().map(|q| q.query.as_str()).collect();
+        let regressed: Vec<&str> = diff.regressed.iter().map(|q| q.query.as_str()).collect();
+        // Biggest change first
+        assert_eq!(improved, ["a", "e"]);
+        assert_eq!(regressed, ["b"]);
+        assert_eq!(diff.num_reordered, 1);
+        assert_eq!(diff.regressed[0].baseline_results, ["🐱"]);
+        assert_eq!(diff.regressed[0].candidate_results, ["🐈"]);
+        assert!((diff.delta.ndcg - (3.95 - 3.15) / 4.0).abs() < TOLERANCE);
+    }
+}
@@ -0,0 +1,102 @@
+// src/global.rs
+//! Process-wide emoji data, loaded on first use, so callers don't have to pass it around
+//!
+//! Searches take a snapshot of the current data, so swapping in reloaded data never blocks
+//! or disturbs searches in flight, they finish on the data they started with.
+
+use crate::constants::{self, EmojiData, Options};
+use crate::describe::EmojiDescription;
+use crate::error::{FfiError, FfiResult};
+use emojis::emoji::Emoji;
+use std::sync::{Arc, RwLock};
+use tracing::{debug, info};
+
+/// The global emoji data, `None` until first used or set
+static GLOBAL_EMOJI_DATA: RwLock<Option<Arc<EmojiData>>> = RwLock::new(None);
+
+/// Get the global emoji data, loading the built-in data on first use
+///
+/// # Returns
+/// A snapshot of the global emoji data, unaffected by later swaps
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn global_emoji_data() -> FfiResult<Arc<EmojiData>> {
+    if let Some(emoji_data) = GLOBAL_EMOJI_DATA.read().unwrap().as_ref() {
+        return Ok(emoji_data.clone());
+    }
+
+    let mut global_emoji_data = GLOBAL_EMOJI_DATA.write().unwrap();
+
+    // Another thread may have loaded the data while we waited for the lock
+    if let Some(emoji_data) = global_emoji_data.as_ref() {
+        return Ok(emoji_data.clone());
+    }
+
+    info!("Loading global emoji data");
+    let emoji_data = Arc::new(constants::load_emoji_data().map_err(FfiError::from)?);
+    *global_emoji_data = Some(emoji_data.clone());
+
+    Ok(emoji_data)
+}
+
+/// Atomically replace the global emoji data
+///
+/// Searches already running keep using the data they started with.
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn set_global_emoji_data(emoji_data: Arc<EmojiData>) {
+    debug!("Swapping global emoji data");
+    *GLOBAL_EMOJI_DATA.write().unwrap() = Some(emoji_data);
+}
+
+/// Atomically replace the global emoji data with an updated copy of it, e.g. with one of its
+/// `Arc` fields swapped, loading the built-in data first if needed
+pub fn update_global_emoji_data(update: impl FnOnce(&EmojiData) -> EmojiData) -> FfiResult<()> {
+    // Make sure there is data to update
+    global_emoji_data()?;
+
+    let mut global_emoji_data = GLOBAL_EMOJI_DATA.write().unwrap();
+    if let Some(emoji_data) = global_emoji_data.as_ref() {
+        debug!("Updating global emoji data");
+        *global_emoji_data = Some(Arc::new(update(emoji_data)));
+    }
+
+    Ok(())
+}
+
+/// Reload the built-in emoji data and swap it in as the global emoji data
+///
+/// The data is loaded before the swap, so searches are never blocked by the loading.
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn reload_global_emoji_data() -> FfiResult<()> {
+    let emoji_data = constants::load_emoji_data().map_err(FfiError::from)?;
+    set_global_emoji_data(Arc::new(emoji_data));
+    Ok(())
+}
+
+/// Search emojis with the global emoji data, see `search_emojis`
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_emojis_global(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+) -> FfiResult<Vec<Emoji>> {
+    let emoji_data = global_emoji_data()?;
+    crate::search_emojis(input, max_limit, options, &emoji_data).await
+}
+
+/// Search best matching emojis with the global emoji data, see `search_best_matching_emojis`
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_best_matching_emojis_global(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+) -> FfiResult<Vec<Emoji>> {
+    let emoji_data = global_emoji_data()?;
+    crate::search_best_matching_emojis(input, max_limit, options, &emoji_data).await
+}
+
+/// Describe an emoji with the global emoji data, see `describe`
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn describe_global(input: &str) -> FfiResult<EmojiDescription> {
+    let emoji_data = global_emoji_data()?;
+    crate::describe(input, &emoji_data)
+}
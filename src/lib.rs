@@ -4,19 +4,60 @@
 //! This library provides functionality to search for emojis based on text input,
 //! with support for single word searches, multiple word searches, and best matching searches.
 
+use std::sync::Arc;
 use tracing::{debug, error, trace};
 
+// UniFFI bindings, the wasm32 build has its own JavaScript bindings in `wasm`
+#[cfg(not(target_arch = "wasm32"))]
+uniffi::setup_scaffolding!();
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod capi;
 pub mod constants;
+pub mod coverage;
+pub mod custom_emoji;
+pub mod describe;
 pub mod error;
+pub mod evaluate;
+pub mod global;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod search;
+pub mod user_dictionary;
 pub mod utils;
+pub mod validate;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+#[cfg(feature = "watch")]
+pub mod watch;
 
 use constants::{EmojiData, Options};
+use coverage::{add_fallback_keywords, compute_coverage, CoverageReport, DEFAULT_MIN_KEYWORDS};
+use custom_emoji::{CustomEmoji, SearchResult};
+use describe::{describe_emoji, EmojiDescription};
 use emojis::{emoji::Emoji, get};
-use error::FfiResult;
-use search::{match_emoji_to_words, match_emojis_to_word};
+use error::{FfiError, FfiResult};
+use evaluate::{diff_evaluations, evaluate, parse_judgments, EvaluationDiff, EvaluationReport};
+use search::{
+    compose_emoji_sequence, emojify_words, match_hits_to_word, match_hits_to_words,
+    match_items_to_word, match_items_to_words, match_phrase_segments, render_emojified,
+    resolve_flag, suggest_emojis_for_message, EmojifyMode, EmojifyToken, MessageSuggestions,
+    RankedItem, SearchHit,
+};
 use utils::nlp::stemmer::stem_word;
 use utils::preprocess::pre_process_string;
+use validate::{validate_data, ValidationReport};
+
+/// Load the built-in emoji data, to be shared by all searches
+///
+/// # Returns
+/// The emoji data, or an error if the embedded data files can't be parsed
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn load_emoji_data() -> FfiResult<Arc<EmojiData>> {
+    constants::load_emoji_data()
+        .map(Arc::new)
+        .map_err(FfiError::from)
+}
 
 /// Main entry point for searching emojis
 ///
@@ -30,12 +71,33 @@ use utils::preprocess::pre_process_string;
 ///
 /// # Returns
 /// A vector of matching emoji strings
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
 pub async fn search_emojis(
     input: &str,
     max_limit: Option<u32>,
     options: Option<Options>,
     emoji_data: &EmojiData,
 ) -> FfiResult<Vec<Emoji>> {
+    let hits = search_hits(input, max_limit, options, emoji_data).await?;
+    Ok(hits.into_iter().map(|hit| hit.emoji).collect())
+}
+
+/// Search emojis like `search_emojis`, with the keyword each emoji matched
+///
+/// # Arguments
+/// * `input` - The search query string
+/// * `max_limit` - Maximum number of results to return (default: 24)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// A vector of matching emojis with their matched keywords
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_hits(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<SearchHit>> {
     let max_limit = max_limit.unwrap_or(24);
     let options = options.unwrap_or_default();
 
@@ -54,7 +116,7 @@ pub async fn search_emojis(
     if let Some(em) = get(input.as_str()) {
         if emoji_data.emoji_set.contains(&em) {
             debug!("Input is a known emoji, returning it directly");
-            return Ok(vec![em]);
+            return Ok(vec![SearchHit::from(em)]);
         }
     } else {
         error!("{} is not a recongized emoji", input);
@@ -65,10 +127,12 @@ pub async fn search_emojis(
 
     let results = if is_single_word_input {
         trace!("Processing as single word input");
-        match_emojis_to_word(&input, emoji_data, &options).await
+        match_hits_to_word(&input, emoji_data, &options).await
     } else {
         trace!("Processing as multiple words input");
-        match_emoji_to_words(&input, emoji_data, &options).await
+        let hits = match_hits_to_words(&input, emoji_data, &options).await;
+        let hits = with_phrases_ranked(&input, hits, emoji_data, &options).await;
+        with_described_emoji_ranked(&input, hits)
     };
 
     // Truncate results to the specified limit
@@ -89,12 +153,34 @@ pub async fn search_emojis(
 ///
 /// # Returns
 /// A vector of best matching emoji strings
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
 pub async fn search_best_matching_emojis(
     input: &str,
     max_limit: Option<u32>,
     options: Option<Options>,
     emoji_data: &EmojiData,
 ) -> FfiResult<Vec<Emoji>> {
+    let hits = search_best_matching_hits(input, max_limit, options, emoji_data).await?;
+    Ok(hits.into_iter().map(|hit| hit.emoji).collect())
+}
+
+/// Search best matching emojis like `search_best_matching_emojis`, with the keyword each
+/// emoji matched
+///
+/// # Arguments
+/// * `input` - The search query string
+/// * `max_limit` - Maximum number of results to return (default: 24)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// A vector of best matching emojis with their matched keywords
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_best_matching_hits(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<SearchHit>> {
     let max_limit = max_limit.unwrap_or(24);
     let options = options.unwrap_or_default();
 
@@ -114,32 +200,439 @@ pub async fn search_best_matching_emojis(
 
     let results = if is_single_word_input {
         trace!("Processing best matching for single word input");
-        let mut emojis = match_emojis_to_word(&input, emoji_data, &options).await;
+        let mut hits = match_hits_to_word(&input, emoji_data, &options).await;
 
         // If no results, try with stemmed input
-        if emojis.is_empty() {
+        if hits.is_empty() {
             let stemmed_input = stem_word(&input);
             if stemmed_input != input {
-                emojis = match_emojis_to_word(&stemmed_input, emoji_data, &options).await;
+                hits = match_hits_to_word(&stemmed_input, emoji_data, &options).await;
             }
         }
 
-        emojis
+        hits
     } else {
         trace!("Processing best matching for multiple words input");
         // First try regular multiple words search
-        let emojis = match_emoji_to_words(&input, emoji_data, &options).await;
+        let hits = match_hits_to_words(&input, emoji_data, &options).await;
 
         // If no results, fall back to best matching search
-        if emojis.is_empty() {
-            match_emoji_to_words(&input, emoji_data, &options).await
+        let hits = if hits.is_empty() {
+            match_hits_to_words(&input, emoji_data, &options).await
         } else {
-            emojis
+            hits
+        };
+
+        let hits = with_phrases_ranked(&input, hits, emoji_data, &options).await;
+        with_described_emoji_ranked(&input, hits)
+    };
+
+    // Truncate results to the specified limit
+    let limited_results: Vec<SearchHit> = results.into_iter().take(max_limit as usize).collect();
+
+    Ok(limited_results)
+}
+
+/// Search emojis and custom emojis, ranked together
+///
+/// Like `search_emojis`, with the custom emojis of the emoji data matched against their
+/// name, id and keywords by the same rules as the Unicode emojis.
+///
+/// # Arguments
+/// * `input` - The search query string, e.g. "parrot" or ":partyparrot:"
+/// * `max_limit` - Maximum number of results to return (default: 24)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// A vector of matching Unicode and custom emojis
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_items(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<SearchResult>> {
+    let max_limit = max_limit.unwrap_or(24);
+    let options = options.unwrap_or_default();
+
+    debug!(
+        "Searching items with input: '{}', max_limit: {}",
+        input, max_limit
+    );
+
+    let input = pre_process_string(input).trim().to_string();
+    if input.is_empty() {
+        debug!("Empty input, returning empty results");
+        return Ok(Vec::new());
+    }
+
+    // Return the input itself if it is an emoji
+    if let Some(em) = get(input.as_str()) {
+        if emoji_data.emoji_set.contains(&em) {
+            debug!("Input is a known emoji, returning it directly");
+            return Ok(vec![SearchResult::from(em)]);
         }
+    }
+
+    // Determine whether it's a single word or multiple words input
+    let is_single_word_input = !input.contains(' ');
+
+    let results = if is_single_word_input {
+        trace!("Processing as single word input");
+        match_items_to_word(&input, emoji_data, &options).await
+    } else {
+        trace!("Processing as multiple words input");
+        let items = match_items_to_words(&input, emoji_data, &options).await;
+        let items = with_phrases_ranked(&input, items, emoji_data, &options).await;
+        with_described_emoji_ranked(&input, items)
     };
 
     // Truncate results to the specified limit
-    let limited_results: Vec<Emoji> = results.into_iter().take(max_limit as usize).collect();
+    let limited_results = results.into_iter().take(max_limit as usize).collect();
 
     Ok(limited_results)
 }
+
+/// Search best matching emojis and custom emojis, ranked together
+///
+/// Like `search_best_matching_emojis`, with the custom emojis of the emoji data matched
+/// against their name, id and keywords by the same rules as the Unicode emojis.
+///
+/// # Arguments
+/// * `input` - The search query string, e.g. "parrots" or "dancing parrot"
+/// * `max_limit` - Maximum number of results to return (default: 24)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// A vector of best matching Unicode and custom emojis
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn search_best_matching_items(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<SearchResult>> {
+    let max_limit = max_limit.unwrap_or(24);
+    let options = options.unwrap_or_default();
+
+    debug!(
+        "Searching best matching items with input: '{}', max_limit: {}",
+        input, max_limit
+    );
+
+    let input = pre_process_string(input).trim().to_string();
+    if input.is_empty() {
+        debug!("Empty input, returning empty results");
+        return Ok(Vec::new());
+    }
+
+    // Determine whether it's a single word or multiple words input
+    let is_single_word_input = !input.contains(' ');
+
+    let results = if is_single_word_input {
+        trace!("Processing best matching for single word input");
+        let mut items = match_items_to_word(&input, emoji_data, &options).await;
+
+        // If no results, try with stemmed input
+        if items.is_empty() {
+            let stemmed_input = stem_word(&input);
+            if stemmed_input != input {
+                items = match_items_to_word(&stemmed_input, emoji_data, &options).await;
+            }
+        }
+
+        items
+    } else {
+        trace!("Processing best matching for multiple words input");
+        let items = match_items_to_words(&input, emoji_data, &options).await;
+        let items = with_phrases_ranked(&input, items, emoji_data, &options).await;
+        with_described_emoji_ranked(&input, items)
+    };
+
+    // Truncate results to the specified limit
+    let limited_results = results.into_iter().take(max_limit as usize).collect();
+
+    Ok(limited_results)
+}
+
+/// Add custom emojis to a copy of the emoji data, replacing the ones with the same ids
+///
+/// # Arguments
+/// * `custom_emojis` - The custom emojis to add
+///
+/// # Returns
+/// The emoji data with the custom emojis, the given emoji data is unchanged
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn with_custom_emojis(
+    custom_emojis: Vec<CustomEmoji>,
+    emoji_data: &EmojiData,
+) -> Arc<EmojiData> {
+    let mut emoji_data = emoji_data.clone();
+    emoji_data.add_custom_emojis(custom_emojis);
+
+    Arc::new(emoji_data)
+}
+
+/// Evaluate the search against judged queries, e.g. before and after a ranking change
+///
+/// # Arguments
+/// * `judgments` - JSON map from query to its relevant emojis, most relevant first
+/// * `k` - Number of top results evaluated per query (default: 10)
+/// * `best_matching` - Whether to evaluate `search_best_matching_emojis` instead of
+///   `search_emojis`
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// The MRR, nDCG, precision and recall at k of every query and their averages, or an
+/// error if the judgments aren't valid
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn evaluate_search(
+    judgments: &str,
+    k: Option<u32>,
+    best_matching: bool,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<EvaluationReport> {
+    let judgments = parse_judgments(judgments).map_err(FfiError::from)?;
+
+    evaluate(&judgments, k.unwrap_or(10), |query, k| {
+        let options = options.clone();
+        async move {
+            if best_matching {
+                search_best_matching_emojis(&query, Some(k), options, emoji_data).await
+            } else {
+                search_emojis(&query, Some(k), options, emoji_data).await
+            }
+        }
+    })
+    .await
+}
+
+/// Compare a candidate evaluation with a baseline evaluation of the same judgments
+///
+/// # Returns
+/// The change of the averaged metrics, and the queries that improved or regressed
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn diff_evaluation_reports(
+    baseline: EvaluationReport,
+    candidate: EvaluationReport,
+) -> EvaluationDiff {
+    diff_evaluations(&baseline, &candidate)
+}
+
+/// Validate the contents of keyword data files, e.g. before shipping edited files
+///
+/// # Arguments
+/// * `emoji_keywords` - JSON map from emoji to its keywords
+/// * `keyword_most_relevant_emoji` - JSON map from keyword to emoji
+/// * `emoji_glossary` - JSON map from keyword to emojis
+///
+/// # Returns
+/// A report of unknown emojis, duplicate and equivalent keywords, orphaned most relevant
+/// emojis, emojis without keywords and glossary inconsistencies
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn validate_emoji_data(
+    emoji_keywords: &str,
+    keyword_most_relevant_emoji: &str,
+    emoji_glossary: &str,
+) -> FfiResult<ValidationReport> {
+    validate_data(emoji_keywords, keyword_most_relevant_emoji, emoji_glossary)
+        .map_err(FfiError::from)
+}
+
+/// Report the emojis with missing or thin keyword lists, which search can't or barely reach
+///
+/// # Arguments
+/// * `min_keywords` - Emojis with fewer keywords are reported as thin (default: 3)
+///
+/// # Returns
+/// The coverage report, with counts by group and Unicode version
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn keyword_coverage(min_keywords: Option<u32>, emoji_data: &EmojiData) -> CoverageReport {
+    compute_coverage(min_keywords.unwrap_or(DEFAULT_MIN_KEYWORDS), emoji_data)
+}
+
+/// Give every emoji without keywords keywords generated from its CLDR name and shortcodes
+///
+/// # Returns
+/// A copy of the emoji data where every emoji is searchable, the given emoji data is unchanged
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn with_fallback_keywords(emoji_data: &EmojiData) -> Arc<EmojiData> {
+    let mut emoji_data = emoji_data.clone();
+    add_fallback_keywords(&mut emoji_data);
+
+    Arc::new(emoji_data)
+}
+
+/// Rank the emojis of the phrases inside a multiple words input above the other matches
+///
+/// e.g. "red heart balloon" ranks ❤️ for "red heart" and 🎈 for "balloon" first. Emojis
+/// with a keyword containing the whole input in order stay above the phrases.
+async fn with_phrases_ranked<T: RankedItem>(
+    input: &str,
+    items: Vec<T>,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<T> {
+    let Some(phrase_matches) = match_phrase_segments(input, emoji_data, options).await else {
+        return items;
+    };
+
+    let (mut ranked, mut items): (Vec<T>, Vec<T>) = items.into_iter().partition(|item| {
+        phrase_matches
+            .in_order_emojis
+            .iter()
+            .any(|emoji| item.is_emoji(emoji))
+    });
+
+    for hit in phrase_matches.hits {
+        if ranked.iter().any(|item| item.is_emoji(&hit.emoji)) {
+            continue;
+        }
+
+        let item = match items.iter().position(|item| item.is_emoji(&hit.emoji)) {
+            Some(idx) => items.remove(idx),
+            None => T::from_hit(hit),
+        };
+        ranked.push(item);
+    }
+
+    trace!("Ranked {} emojis of phrases first", ranked.len());
+    ranked.extend(items);
+    ranked
+}
+
+/// Rank the emoji described by a multiple words input first, even when it has no keywords
+///
+/// e.g. a composed sequence, 👩🏽‍🚒 for "woman firefighter medium skin", or a country flag,
+/// 🇨🇮 for "côte d'ivoire".
+fn with_described_emoji_ranked<T: RankedItem>(input: &str, mut emojis: Vec<T>) -> Vec<T> {
+    if let Some(composed) = compose_emoji_sequence(input) {
+        trace!("Input describes {}, ranking it first", composed);
+        let item = match emojis.iter().position(|emoji| emoji.is_emoji(&composed)) {
+            Some(idx) => emojis.remove(idx),
+            None => T::from(composed),
+        };
+        emojis.insert(0, item);
+    } else if let Some(flag) = resolve_flag(input) {
+        trace!("Input describes {}, ranking it first", flag);
+        let item = match emojis.iter().position(|emoji| emoji.is_emoji(&flag)) {
+            Some(idx) => emojis.remove(idx),
+            None => T::from(flag),
+        };
+        emojis.insert(0, item);
+    }
+
+    emojis
+}
+
+/// Suggest emojis for the words of a free text, e.g. "I love pizza and cats"
+///
+/// Function words are skipped, and each remaining word that maps to an emoji is returned
+/// as a token with its byte span in the input and its suggestions ranked by confidence.
+///
+/// # Arguments
+/// * `input` - The free text to annotate
+/// * `max_suggestions` - Maximum number of suggestions per word (default: 3)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// A vector of tokens with suggested emojis, in input order
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn emojify(
+    input: &str,
+    max_suggestions: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<Vec<EmojifyToken>> {
+    let max_suggestions = max_suggestions.unwrap_or(3);
+    let options = options.unwrap_or_default();
+
+    debug!(
+        "Emojifying input: '{}', max_suggestions: {}",
+        input, max_suggestions
+    );
+
+    if input.trim().is_empty() {
+        debug!("Empty input, returning empty results");
+        return Ok(Vec::new());
+    }
+
+    Ok(emojify_words(input, emoji_data, &options, max_suggestions as usize).await)
+}
+
+/// Emojify a free text and render it with the best suggestion of each word inserted
+///
+/// # Arguments
+/// * `input` - The free text to emojify
+/// * `mode` - Whether emojis are appended to, replace, or are added after the words
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// The emojified text, e.g. "I love ❤️ pizza 🍕 and cats 🐈"
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn emojify_text(
+    input: &str,
+    mode: EmojifyMode,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<String> {
+    let tokens = emojify(input, Some(1), options, emoji_data).await?;
+    Ok(render_emojified(input, &tokens, mode))
+}
+
+/// Suggest emojis for a whole message, one per concept
+///
+/// Unlike `search_best_matching_emojis`, which favors emojis matching the most words,
+/// the message is split into concepts and each concept gets its own best emoji.
+///
+/// # Arguments
+/// * `input` - The message, e.g. "going to the beach with my dog"
+/// * `max_limit` - Maximum number of suggestions per concept and overall (default: 24)
+/// * `options` - Custom options for the search algorithm
+///
+/// # Returns
+/// The concepts with their suggestions and the overall top emojis
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export(async_runtime = "tokio"))]
+pub async fn suggest_for_message(
+    input: &str,
+    max_limit: Option<u32>,
+    options: Option<Options>,
+    emoji_data: &EmojiData,
+) -> FfiResult<MessageSuggestions> {
+    let max_limit = max_limit.unwrap_or(24);
+    let options = options.unwrap_or_default();
+
+    debug!(
+        "Suggesting emojis for message: '{}', max_limit: {}",
+        input, max_limit
+    );
+
+    let input = pre_process_string(input).trim().to_string();
+    if input.is_empty() {
+        debug!("Empty input, returning empty results");
+        return Ok(MessageSuggestions::default());
+    }
+
+    Ok(suggest_emojis_for_message(&input, emoji_data, &options, max_limit as usize).await)
+}
+
+/// Describe an emoji with its name, group, shortcodes and keywords
+///
+/// # Arguments
+/// * `input` - The emoji, e.g. "😀"
+///
+/// # Returns
+/// The description, or an invalid input error if the input is not a known emoji
+#[cfg_attr(not(target_arch = "wasm32"), uniffi::export)]
+pub fn describe(input: &str, emoji_data: &EmojiData) -> FfiResult<EmojiDescription> {
+    debug!("Describing input: '{}'", input);
+
+    match get(input.trim()) {
+        Some(emoji) => Ok(describe_emoji(&emoji, emoji_data)),
+        None => Err(FfiError::InvalidInput(format!(
+            "{} is not a recognized emoji",
+            input
+        ))),
+    }
+}
@@ -1,34 +1,466 @@
+mod cli;
+
+use clap::{Args, Parser, Subcommand};
+use cli::batch::run_batch;
+use cli::format::{to_records, write_description, write_records, Format};
+use cli::lsp::run_language_server;
+use cli::picker::pick_emoji;
+use cli::serve::run_server;
+use cli::{search_hits_with_mode, search_with_mode, Mode};
 use emoji_search::{
-    constants::{self},
-    search_best_matching_emojis, search_emojis,
+    constants::{self, EmojiData, EmojiDataPaths},
+    coverage::{compute_coverage, fallback_keywords, DEFAULT_MIN_KEYWORDS},
+    describe,
+    error::FfiError,
+    evaluate::{diff_evaluations, evaluate, parse_judgments, EvaluationReport, Judgment},
+    search::{parse_skin_tone, SearchHit},
+    utils::get_emojis::{group_name, group_slug, parse_group, GROUPS},
+    utils::preprocess::pre_process_string,
+    validate::validate_data_files,
 };
-use env_logger;
+use emojis::emoji::{Group, SkinTone};
 use log::info;
-use serde_cbor;
+use std::collections::BTreeMap;
+use std::error::Error;
 use std::io::Write;
-use std::{env::args, ops::Deref};
+use std::process::ExitCode;
+
+/// Locales the keyword data is available in
+const SUPPORTED_LOCALES: [&str; 1] = ["en"];
+
+/// Search emojis by keyword from the command line
+#[derive(Parser)]
+#[command(name = "emoji-search", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search emojis, narrowing the results as more words are typed
+    Search(SearchArgs),
+    /// Search best matching emojis, also matching stemmed words
+    Best(SearchArgs),
+    /// Show the name, group, shortcodes and keywords of an emoji
+    Describe {
+        /// The emoji to describe, e.g. 😀
+        emoji: String,
+        /// Output format of the description
+        #[arg(short, long, value_enum, default_value_t = Format::Plain)]
+        format: Format,
+    },
+    /// Print random emojis
+    Random {
+        /// Number of emojis to print
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+        #[command(flatten)]
+        filters: FilterArgs,
+    },
+    /// List the emoji groups that can be used with --group
+    ListGroups,
+    /// Search every line of a file or stdin as a query, printing JSONL results in input order
+    Batch {
+        /// File with one query per line, reads stdin when omitted or "-"
+        input: Option<std::path::PathBuf>,
+        /// Maximum number of results per query
+        #[arg(short, long, default_value_t = 24)]
+        limit: u32,
+        /// How the queries are matched
+        #[arg(short, long, value_enum, default_value_t = Mode::Standard)]
+        mode: Mode,
+        /// Number of queries searched in parallel
+        #[arg(short = 'j', long, default_value_t = 8)]
+        jobs: usize,
+    },
+    /// Serve search and describe lookups over a local HTTP/JSON API
+    Serve {
+        /// Address to listen on
+        #[arg(short, long, default_value = "127.0.0.1:8787")]
+        addr: std::net::SocketAddr,
+    },
+    /// Run a language server on stdio completing `:keyword` with emojis in Markdown, commit
+    /// messages and comments
+    Lsp,
+    /// Check keyword data files and print a JSON report of the problems found, exits with
+    /// status 1 if there are errors
+    Validate {
+        /// Keywords file to check instead of the embedded one
+        #[arg(long)]
+        keywords: Option<std::path::PathBuf>,
+        /// Most relevant emoji file to check instead of the embedded one
+        #[arg(long)]
+        most_relevant: Option<std::path::PathBuf>,
+        /// Glossary file to check instead of the embedded one
+        #[arg(long)]
+        glossary: Option<std::path::PathBuf>,
+    },
+    /// Print a JSON report of the emojis with missing or thin keywords, by group and Unicode
+    /// version
+    Coverage {
+        /// Emojis with fewer keywords are reported as thin
+        #[arg(long, default_value_t = DEFAULT_MIN_KEYWORDS)]
+        min_keywords: u32,
+        /// Print generated fallback keywords of the emojis without keywords instead, as a
+        /// JSON map that can be merged into the keywords file
+        #[arg(long)]
+        fallback: bool,
+    },
+    /// Evaluate search results against judged queries and print a JSON report of MRR, nDCG,
+    /// precision and recall at k, or a diff when a comparison mode or data pack is given
+    Evaluate {
+        /// JSON map from query to its relevant emojis, most relevant first
+        judgments: std::path::PathBuf,
+        /// Number of top results evaluated per query
+        #[arg(short, default_value_t = 10)]
+        k: u32,
+        /// How the queries are matched
+        #[arg(short, long, value_enum, default_value_t = Mode::Standard)]
+        mode: Mode,
+        /// Data pack directory with files named like the ones in data/
+        #[arg(long)]
+        data: Option<std::path::PathBuf>,
+        /// Mode to compare with, defaults to --mode
+        #[arg(long, value_enum)]
+        compare_mode: Option<Mode>,
+        /// Data pack directory to compare with, defaults to --data
+        #[arg(long)]
+        compare_data: Option<std::path::PathBuf>,
+    },
+    /// Pick an emoji interactively, copy it to the clipboard and print it
+    Pick {
+        /// Initial search query
+        query: Vec<String>,
+        /// Maximum number of results
+        #[arg(short, long, default_value_t = 100)]
+        limit: u32,
+    },
+}
+
+#[derive(Args)]
+struct SearchArgs {
+    /// The search query, e.g. "smiling face"
+    #[arg(required = true)]
+    query: Vec<String>,
+    /// Maximum number of results
+    #[arg(short, long, default_value_t = 24)]
+    limit: u32,
+    /// How the query is matched
+    #[arg(short, long, value_enum)]
+    mode: Option<Mode>,
+    /// Output format of the results
+    #[arg(short, long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+    #[command(flatten)]
+    filters: FilterArgs,
+}
+
+#[derive(Args)]
+struct FilterArgs {
+    /// Only show emojis of this group, see list-groups
+    #[arg(short, long)]
+    group: Option<String>,
+    /// Skin tone applied to emojis that support it, e.g. "medium-dark"
+    #[arg(short, long)]
+    skin_tone: Option<String>,
+    /// Locale of the query and keywords
+    #[arg(long, default_value = "en")]
+    locale: String,
+}
+
+/// Filters parsed and validated from the command line
+struct Filters {
+    group: Option<Group>,
+    skin_tone: Option<SkinTone>,
+}
+
+impl FilterArgs {
+    fn parse(&self) -> Result<Filters, FfiError> {
+        let locale = self.locale.to_lowercase();
+        if !SUPPORTED_LOCALES
+            .iter()
+            .any(|supported| locale == *supported || locale.starts_with(&format!("{supported}-")))
+        {
+            return Err(FfiError::InvalidInput(format!(
+                "unsupported locale '{}', supported locales: {}",
+                self.locale,
+                SUPPORTED_LOCALES.join(", ")
+            )));
+        }
+
+        let group = match &self.group {
+            Some(group) => Some(parse_group(group).ok_or_else(|| {
+                FfiError::InvalidInput(format!(
+                    "unknown group '{}', run list-groups to see the available groups",
+                    group
+                ))
+            })?),
+            None => None,
+        };
+
+        let skin_tone = match &self.skin_tone {
+            Some(skin_tone) => {
+                let words: Vec<String> = pre_process_string(skin_tone)
+                    .split_whitespace()
+                    .map(|s| s.to_string())
+                    .collect();
+                match parse_skin_tone(&words) {
+                    Some((skin_tone, len)) if len == words.len() => Some(skin_tone),
+                    _ => {
+                        return Err(FfiError::InvalidInput(format!(
+                            "unknown skin tone '{}', expected light, medium-light, medium, medium-dark or dark",
+                            skin_tone
+                        )))
+                    }
+                }
+            }
+            None => None,
+        };
+
+        Ok(Filters { group, skin_tone })
+    }
+}
+
+impl Filters {
+    /// Keep the emojis of the group, with the skin tone applied, up to the limit
+    fn apply(&self, hits: Vec<SearchHit>, limit: usize) -> Vec<SearchHit> {
+        hits.into_iter()
+            .filter(|hit| self.group.is_none_or(|group| hit.emoji.group() == group))
+            .map(
+                |SearchHit {
+                     emoji,
+                     matched_keyword,
+                 }| {
+                    let emoji = match self.skin_tone {
+                        Some(skin_tone) => emoji
+                            .with_skin_tone(skin_tone)
+                            .map(|toned| toned.to_owned())
+                            .unwrap_or(emoji),
+                        None => emoji,
+                    };
+                    SearchHit {
+                        emoji,
+                        matched_keyword,
+                    }
+                },
+            )
+            .take(limit)
+            .collect()
+    }
+}
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> ExitCode {
     env_logger::init();
-    // (Optionally initialize tracing subscriber here)
 
-    info!("Starting emoji search example");
+    let cli = Cli::parse();
 
-    // Load the emoji data (using sample data here)
-    let emoji_data = constants::load_emoji_data().unwrap();
+    match run(cli.command).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a subcommand
+async fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Search(args) => run_search(args, Mode::Standard).await?,
+        Command::Best(args) => run_search(args, Mode::Best).await?,
+        Command::Describe { emoji, format } => {
+            let emoji_data = load_emoji_data()?;
+            let description = describe(&emoji, &emoji_data)?;
+            write_description(&mut std::io::stdout().lock(), &description, format)?;
+        }
+        Command::Random { count, filters } => {
+            let filters = filters.parse()?;
+            let emoji_data = load_emoji_data()?;
+
+            let all_emojis: Vec<SearchHit> = emoji_data
+                .emoji_set
+                .iter()
+                .cloned()
+                .map(SearchHit::from)
+                .collect();
+            let mut emojis = filters.apply(all_emojis, usize::MAX);
+
+            for _ in 0..count.min(emojis.len()) {
+                let idx = fastrand::usize(..emojis.len());
+                println!("{}", emojis.swap_remove(idx).emoji);
+            }
+        }
+        Command::ListGroups => {
+            for group in GROUPS {
+                println!("{}\t{}", group_slug(group), group_name(group));
+            }
+        }
+        Command::Batch {
+            input,
+            limit,
+            mode,
+            jobs,
+        } => {
+            let emoji_data = load_emoji_data()?;
+            run_batch(input.as_deref(), mode, limit, jobs, emoji_data).await?;
+        }
+        Command::Serve { addr } => {
+            let emoji_data = load_emoji_data()?;
+            run_server(addr, emoji_data).await?;
+        }
+        Command::Lsp => {
+            let emoji_data = load_emoji_data()?;
+            run_language_server(emoji_data).await;
+        }
+        Command::Validate {
+            keywords,
+            most_relevant,
+            glossary,
+        } => {
+            let paths = EmojiDataPaths {
+                emoji_keywords: keywords,
+                keyword_most_relevant_emoji: most_relevant,
+                emoji_glossary: glossary,
+                top_1000_words: None,
+            };
+            let report = validate_data_files(&paths).map_err(FfiError::from)?;
 
-    // Collect CLI args
-    let arguments: Vec<String> = args().collect();
+            info!(
+                "{} errors, {} warnings",
+                report.num_errors, report.num_warnings
+            );
+            serde_json::to_writer_pretty(std::io::stdout().lock(), &report)?;
+            println!();
 
-    // Perform the search
-    let results =
-        search_best_matching_emojis(arguments[1].as_str(), Some(10), None, &emoji_data).await?;
+            if !report.is_valid() {
+                std::process::exit(1);
+            }
+        }
+        Command::Coverage {
+            min_keywords,
+            fallback,
+        } => {
+            let emoji_data = load_emoji_data()?;
+            let report = compute_coverage(min_keywords, &emoji_data);
+            info!(
+                "{} of {} emojis have no keywords",
+                report.num_missing, report.num_emojis
+            );
 
-    for result in results {
-        println!("{result}");
+            let mut stdout = std::io::stdout().lock();
+            if fallback {
+                let fallback_keywords: BTreeMap<String, Vec<String>> = report
+                    .missing
+                    .iter()
+                    .filter_map(|coverage| emojis::get(&coverage.emoji))
+                    .map(|emoji| (emoji.to_string(), fallback_keywords(&emoji)))
+                    .collect();
+                serde_json::to_writer_pretty(&mut stdout, &fallback_keywords)?;
+            } else {
+                serde_json::to_writer_pretty(&mut stdout, &report)?;
+            }
+            writeln!(stdout)?;
+        }
+        Command::Evaluate {
+            judgments,
+            k,
+            mode,
+            data,
+            compare_mode,
+            compare_data,
+        } => {
+            let judgments =
+                parse_judgments(&std::fs::read_to_string(judgments)?).map_err(FfiError::from)?;
+            let baseline = run_evaluation(&judgments, k, mode, data.as_deref()).await?;
+
+            let mut stdout = std::io::stdout().lock();
+            if compare_mode.is_some() || compare_data.is_some() {
+                let candidate = run_evaluation(
+                    &judgments,
+                    k,
+                    compare_mode.unwrap_or(mode),
+                    compare_data.as_deref().or(data.as_deref()),
+                )
+                .await?;
+                let diff = diff_evaluations(&baseline, &candidate);
+                info!(
+                    "{} queries improved, {} regressed",
+                    diff.improved.len(),
+                    diff.regressed.len()
+                );
+                serde_json::to_writer_pretty(&mut stdout, &diff)?;
+            } else {
+                serde_json::to_writer_pretty(&mut stdout, &baseline)?;
+            }
+            writeln!(stdout)?;
+        }
+        Command::Pick { query, limit } => {
+            let emoji_data = load_emoji_data()?;
+
+            match pick_emoji(&query.join(" "), limit, &emoji_data).await? {
+                Some(emoji) => println!("{emoji}"),
+                // Exit like a shell interrupt when cancelled, so pipelines can tell
+                None => std::process::exit(130),
+            }
+        }
     }
 
-    info!("Example completed successfully");
     Ok(())
 }
+
+/// Run a search subcommand, the mode flag takes precedence over the subcommand's default
+async fn run_search(args: SearchArgs, default_mode: Mode) -> Result<(), Box<dyn Error>> {
+    let filters = args.filters.parse()?;
+    let emoji_data = load_emoji_data()?;
+    let query = args.query.join(" ");
+
+    // Search without a limit when filtering, the limit applies to the filtered results
+    let max_limit = if filters.group.is_some() {
+        u32::MAX
+    } else {
+        args.limit
+    };
+
+    info!("Searching for '{}'", query);
+
+    let results = search_hits_with_mode(
+        &query,
+        args.mode.unwrap_or(default_mode),
+        max_limit,
+        None,
+        &emoji_data,
+    )
+    .await?;
+
+    let results = filters.apply(results, args.limit as usize);
+    let records = to_records(&results, &emoji_data);
+    write_records(&mut std::io::stdout().lock(), &records, args.format)?;
+
+    Ok(())
+}
+
+/// Evaluate a search mode on the data of a data pack, or the embedded data
+async fn run_evaluation(
+    judgments: &[Judgment],
+    k: u32,
+    mode: Mode,
+    data: Option<&std::path::Path>,
+) -> Result<EvaluationReport, FfiError> {
+    let emoji_data = match data {
+        Some(dir) => constants::load_emoji_data_from_paths(&EmojiDataPaths::from_dir(dir))
+            .map_err(FfiError::from)?,
+        None => load_emoji_data()?,
+    };
+
+    evaluate(judgments, k, |query, k| {
+        let emoji_data = &emoji_data;
+        async move { search_with_mode(&query, mode, k, None, emoji_data).await }
+    })
+    .await
+}
+
+fn load_emoji_data() -> Result<EmojiData, FfiError> {
+    constants::load_emoji_data().map_err(FfiError::from)
+}
@@ -0,0 +1,313 @@
+// src/python.rs
+//! Python bindings, built with maturin, e.g. `maturin develop --release`
+//!
+//! ```python
+//! import emoji_search
+//! data = emoji_search.EmojiData(keywords="my-keywords.json")
+//! emoji_search.search_emojis("dog", data, max_limit=5, recently_searched_inputs=["doggo"])
+//! # [{"emoji": "🐶", "name": "dog face", "group": "Animals & Nature"}, ...]
+//!
+//! data = data.with_custom_emojis([{"id": "partyparrot", "name": "party parrot", "image": "parrot.gif"}])
+//! emoji_search.search_items("parrot", data)
+//! # [{"type": "custom", "emoji": ":partyparrot:", "id": "partyparrot", ...}, ...]
+//! ```
+
+// The `#[pyfunction]` and `#[pymethods]` expansions of pyo3 0.22 convert a `PyResult` error
+// into `PyErr`, which clippy reports on the return types of the functions here
+#![allow(clippy::useless_conversion)]
+
+use crate::constants::{load_emoji_data_from_paths, EmojiData, EmojiDataPaths, Options};
+use crate::custom_emoji::{CustomEmoji, SearchResult};
+use crate::error::FfiError;
+use crate::utils::get_emojis::group_name;
+use crate::utils::task::block_on;
+use emojis::emoji::Emoji;
+use pyo3::exceptions::{PyOSError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+impl From<FfiError> for PyErr {
+    fn from(err: FfiError) -> Self {
+        match err {
+            FfiError::Io => PyOSError::new_err(err.to_string()),
+            FfiError::InvalidInput(_) => PyValueError::new_err(err.to_string()),
+            FfiError::Json | FfiError::Search(_) => PyRuntimeError::new_err(err.to_string()),
+        }
+    }
+}
+
+/// Emoji data loaded once and passed to every search
+#[pyclass(name = "EmojiData", module = "emoji_search", frozen)]
+pub struct PyEmojiData(EmojiData);
+
+#[pymethods]
+impl PyEmojiData {
+    /// Load the built-in emoji data, replacing the data files whose paths are given
+    #[new]
+    #[pyo3(signature = (*, keywords=None, most_relevant=None, glossary=None, top_words=None))]
+    fn new(
+        keywords: Option<PathBuf>,
+        most_relevant: Option<PathBuf>,
+        glossary: Option<PathBuf>,
+        top_words: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        let paths = EmojiDataPaths {
+            emoji_keywords: keywords,
+            keyword_most_relevant_emoji: most_relevant,
+            emoji_glossary: glossary,
+            top_1000_words: top_words,
+        };
+        let emoji_data = load_emoji_data_from_paths(&paths).map_err(FfiError::from)?;
+
+        Ok(Self(emoji_data))
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.emoji_keywords.len()
+    }
+
+    /// Copy the data with custom emojis added, given as dicts with an "id", a "name", an
+    /// "image" and optional "keywords"
+    fn with_custom_emojis(&self, custom_emojis: Vec<Bound<'_, PyDict>>) -> PyResult<Self> {
+        let custom_emojis = custom_emojis
+            .iter()
+            .map(to_custom_emoji)
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let mut emoji_data = self.0.clone();
+        emoji_data.add_custom_emojis(custom_emojis);
+        Ok(Self(emoji_data))
+    }
+}
+
+/// Search emojis, see `search_emojis`
+#[pyfunction]
+#[pyo3(signature = (
+    input,
+    emoji_data,
+    *,
+    max_limit=None,
+    custom_emoji_keywords=None,
+    custom_keyword_most_relevant_emoji=None,
+    recently_searched_inputs=None,
+))]
+fn search_emojis<'py>(
+    py: Python<'py>,
+    input: &str,
+    emoji_data: &PyEmojiData,
+    max_limit: Option<u32>,
+    custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+    custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+    recently_searched_inputs: Option<Vec<String>>,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let options = Options::from_emoji_strings(
+        custom_emoji_keywords,
+        custom_keyword_most_relevant_emoji,
+        recently_searched_inputs,
+    )
+    .map_err(FfiError::from)?;
+
+    let emojis = py.allow_threads(|| {
+        block_on(crate::search_emojis(
+            input,
+            max_limit,
+            Some(options),
+            &emoji_data.0,
+        ))
+    })?;
+
+    to_dicts(py, &emojis)
+}
+
+/// Search best matching emojis, see `search_best_matching_emojis`
+#[pyfunction]
+#[pyo3(signature = (
+    input,
+    emoji_data,
+    *,
+    max_limit=None,
+    custom_emoji_keywords=None,
+    custom_keyword_most_relevant_emoji=None,
+    recently_searched_inputs=None,
+))]
+fn search_best_matching_emojis<'py>(
+    py: Python<'py>,
+    input: &str,
+    emoji_data: &PyEmojiData,
+    max_limit: Option<u32>,
+    custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+    custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+    recently_searched_inputs: Option<Vec<String>>,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let options = Options::from_emoji_strings(
+        custom_emoji_keywords,
+        custom_keyword_most_relevant_emoji,
+        recently_searched_inputs,
+    )
+    .map_err(FfiError::from)?;
+
+    let emojis = py.allow_threads(|| {
+        block_on(crate::search_best_matching_emojis(
+            input,
+            max_limit,
+            Some(options),
+            &emoji_data.0,
+        ))
+    })?;
+
+    to_dicts(py, &emojis)
+}
+
+/// Search emojis and custom emojis, see `search_items`
+#[pyfunction]
+#[pyo3(signature = (
+    input,
+    emoji_data,
+    *,
+    max_limit=None,
+    custom_emoji_keywords=None,
+    custom_keyword_most_relevant_emoji=None,
+    recently_searched_inputs=None,
+))]
+fn search_items<'py>(
+    py: Python<'py>,
+    input: &str,
+    emoji_data: &PyEmojiData,
+    max_limit: Option<u32>,
+    custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+    custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+    recently_searched_inputs: Option<Vec<String>>,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let options = Options::from_emoji_strings(
+        custom_emoji_keywords,
+        custom_keyword_most_relevant_emoji,
+        recently_searched_inputs,
+    )
+    .map_err(FfiError::from)?;
+
+    let items = py.allow_threads(|| {
+        block_on(crate::search_items(
+            input,
+            max_limit,
+            Some(options),
+            &emoji_data.0,
+        ))
+    })?;
+
+    to_item_dicts(py, &items)
+}
+
+/// Search best matching emojis and custom emojis, see `search_best_matching_items`
+#[pyfunction]
+#[pyo3(signature = (
+    input,
+    emoji_data,
+    *,
+    max_limit=None,
+    custom_emoji_keywords=None,
+    custom_keyword_most_relevant_emoji=None,
+    recently_searched_inputs=None,
+))]
+fn search_best_matching_items<'py>(
+    py: Python<'py>,
+    input: &str,
+    emoji_data: &PyEmojiData,
+    max_limit: Option<u32>,
+    custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+    custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+    recently_searched_inputs: Option<Vec<String>>,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let options = Options::from_emoji_strings(
+        custom_emoji_keywords,
+        custom_keyword_most_relevant_emoji,
+        recently_searched_inputs,
+    )
+    .map_err(FfiError::from)?;
+
+    let items = py.allow_threads(|| {
+        block_on(crate::search_best_matching_items(
+            input,
+            max_limit,
+            Some(options),
+            &emoji_data.0,
+        ))
+    })?;
+
+    to_item_dicts(py, &items)
+}
+
+#[pymodule]
+fn emoji_search(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEmojiData>()?;
+    m.add_function(wrap_pyfunction!(search_emojis, m)?)?;
+    m.add_function(wrap_pyfunction!(search_best_matching_emojis, m)?)?;
+    m.add_function(wrap_pyfunction!(search_items, m)?)?;
+    m.add_function(wrap_pyfunction!(search_best_matching_items, m)?)?;
+    Ok(())
+}
+
+/// Convert emojis to dicts with their emoji, name and group
+fn to_dicts<'py>(py: Python<'py>, emojis: &[Emoji]) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    emojis
+        .iter()
+        .map(|emoji| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("emoji", emoji.to_string())?;
+            dict.set_item("name", emoji.name())?;
+            dict.set_item("group", group_name(emoji.group()))?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+/// Convert items to dicts with their type and emoji, the name and group of Unicode emojis,
+/// and the id, name and image of custom emojis
+fn to_item_dicts<'py>(
+    py: Python<'py>,
+    items: &[SearchResult],
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    items
+        .iter()
+        .map(|item| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("emoji", item.to_string())?;
+            match item {
+                SearchResult::Unicode { emoji } => {
+                    dict.set_item("type", "unicode")?;
+                    dict.set_item("name", emoji.name())?;
+                    dict.set_item("group", group_name(emoji.group()))?;
+                }
+                SearchResult::Custom { emoji } => {
+                    dict.set_item("type", "custom")?;
+                    dict.set_item("id", &emoji.id)?;
+                    dict.set_item("name", &emoji.name)?;
+                    dict.set_item("image", &emoji.image)?;
+                }
+            }
+            Ok(dict)
+        })
+        .collect()
+}
+
+/// Read a custom emoji from a dict with an "id", a "name", an "image" and optional "keywords"
+fn to_custom_emoji(dict: &Bound<'_, PyDict>) -> PyResult<CustomEmoji> {
+    let get = |key: &str| -> PyResult<String> {
+        dict.get_item(key)?
+            .ok_or_else(|| PyValueError::new_err(format!("custom emoji has no \"{}\"", key)))?
+            .extract()
+    };
+
+    let keywords = match dict.get_item("keywords")? {
+        Some(keywords) => keywords.extract()?,
+        None => Vec::new(),
+    };
+
+    Ok(CustomEmoji {
+        id: get("id")?,
+        name: get("name")?,
+        image: get("image")?,
+        keywords,
+    })
+}
@@ -1,5 +1,7 @@
 // src/search/best_matching.rs
 use crate::constants::{EmojiData, Options};
+use crate::custom_emoji::SearchResult;
+use crate::search::hit::SearchHit;
 use crate::utils::nlp::parts_of_speech::filter_parts_of_speech;
 use crate::utils::nlp::stemmer::stem_word;
 use crate::utils::preprocess::pre_process_string;
@@ -15,6 +17,8 @@ struct Attributes {
     num_exact_stemmed_word_matches: usize,
     num_prefix_word_matches: usize,
     num_prefix_stemmed_word_matches: usize,
+    /// The keyword matching the most input words
+    matched_keyword: Option<String>,
 }
 
 /// Search for best matching emojis for input with multiple words
@@ -26,11 +30,94 @@ pub async fn match_emoji_to_words(
     emoji_data: &EmojiData,
     options: &Options,
 ) -> Vec<Emoji> {
+    match_hits_to_words(input_words, emoji_data, options)
+        .await
+        .into_iter()
+        .map(|hit| hit.emoji)
+        .collect()
+}
+
+/// Search for best matching emojis for input with multiple words, with the keywords they
+/// matched
+pub async fn match_hits_to_words(
+    input_words: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<SearchHit> {
     debug!("Searching best matching emojis for: {}", input_words);
 
-    // Create owned copies of the option values to avoid borrowing issues
-    let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
+    let (filtered_input_words, stemmed_input_words) = get_filtered_and_stemmed_words(input_words);
+
+    // Extract sorted emojis
+    let results: Vec<SearchHit> = rank_emojis_for_words(
+        &filtered_input_words,
+        &stemmed_input_words,
+        emoji_data,
+        options,
+    )
+    .into_iter()
+    .map(|(emoji, attributes)| SearchHit {
+        emoji: emoji.clone(),
+        matched_keyword: attributes.matched_keyword,
+    })
+    .collect();
+
+    debug!("Found {} best matching emojis", results.len());
+    results
+}
+
+/// Search for best matching emojis and custom emojis for input with multiple words
+///
+/// Custom emojis are ranked by the same attributes as the Unicode emojis, and come after
+/// the Unicode emojis they tie with.
+pub async fn match_items_to_words(
+    input_words: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<SearchResult> {
+    debug!("Searching best matching items for: {}", input_words);
+
+    let (filtered_input_words, stemmed_input_words) = get_filtered_and_stemmed_words(input_words);
+
+    let mut items_attributes: Vec<(SearchResult, Attributes)> = rank_emojis_for_words(
+        &filtered_input_words,
+        &stemmed_input_words,
+        emoji_data,
+        options,
+    )
+    .into_iter()
+    .map(|(emoji, attributes)| (SearchResult::from(emoji.clone()), attributes))
+    .collect();
+
+    let input_words: Vec<&str> = filtered_input_words
+        .iter()
+        .chain(&stemmed_input_words)
+        .map(String::as_str)
+        .collect();
+    let custom_emojis = emoji_data.custom_emojis.candidates(&input_words);
+    items_attributes.extend(
+        custom_emojis
+            .into_iter()
+            .filter_map(|(custom_emoji, keywords)| {
+                get_emoji_best_attributes(&filtered_input_words, &stemmed_input_words, keywords)
+                    .map(|attrs| (SearchResult::from(custom_emoji.clone()), attrs))
+            }),
+    );
+
+    // Stable sort, so custom emojis stay after the Unicode emojis they tie with
+    items_attributes.sort_by(|(_, a), (_, b)| compare_attributes(a, b));
+
+    let results: Vec<SearchResult> = items_attributes
+        .into_iter()
+        .map(|(item, _attributes)| item)
+        .collect();
 
+    debug!("Found {} best matching items", results.len());
+    results
+}
+
+/// Filter parts of speech of the input words, and stem the remaining words
+fn get_filtered_and_stemmed_words(input_words: &str) -> (Vec<String>, Vec<String>) {
     // Pre-process and split input into words
     let input_words_array: Vec<String> = input_words.split(' ').map(|s| s.to_string()).collect();
 
@@ -43,19 +130,33 @@ pub async fn match_emoji_to_words(
         .map(|word| stem_word(word))
         .collect();
 
+    (filtered_input_words, stemmed_input_words)
+}
+
+/// Get the emojis matching the input words with their attributes, sorted by attributes
+fn rank_emojis_for_words<'a>(
+    filtered_input_words: &[String],
+    stemmed_input_words: &[String],
+    emoji_data: &'a EmojiData,
+    options: &Options,
+) -> Vec<(&'a Emoji, Attributes)> {
+    // Create owned copies of the option values to avoid borrowing issues
+    let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
+
     let mut emojis_attributes: Vec<(&Emoji, Attributes)> = Vec::new();
 
-    // Use rayon to process emojis in parallel
+    // Use rayon to process emojis in parallel, there are no threads on wasm32
+    #[cfg(not(target_arch = "wasm32"))]
     use rayon::prelude::*;
 
-    let emoji_data_ref = &emoji_data;
     let custom_emoji_keywords_ref = &custom_emoji_keywords;
-    let filtered_input_words_ref = &filtered_input_words;
-    let stemmed_input_words_ref = &stemmed_input_words;
 
-    let parallel_results: Vec<_> = emoji_data_ref
-        .emoji_keywords
-        .par_iter()
+    #[cfg(not(target_arch = "wasm32"))]
+    let emoji_keywords = emoji_data.emoji_keywords.par_iter();
+    #[cfg(target_arch = "wasm32")]
+    let emoji_keywords = emoji_data.emoji_keywords.iter();
+
+    let parallel_results: Vec<_> = emoji_keywords
         .filter_map(|(emoji, keywords)| {
             let all_keywords = if let Some(custom_kw) = custom_emoji_keywords_ref.get(emoji) {
                 let mut combined = keywords.clone();
@@ -65,11 +166,8 @@ pub async fn match_emoji_to_words(
                 keywords.clone()
             };
 
-            let emoji_best_attributes = get_emoji_best_attributes(
-                filtered_input_words_ref,
-                stemmed_input_words_ref,
-                &all_keywords,
-            );
+            let emoji_best_attributes =
+                get_emoji_best_attributes(filtered_input_words, stemmed_input_words, &all_keywords);
 
             emoji_best_attributes.map(|attrs| (emoji, attrs))
         })
@@ -77,19 +175,12 @@ pub async fn match_emoji_to_words(
 
     emojis_attributes.extend(parallel_results);
 
-    // Sort emojis by attributes
-    emojis_attributes.sort_by(|(_, a), (_, b)| compare_attributes(a, b));
+    // Sort emojis by attributes, ties in emoji order so results don't depend on map order
+    emojis_attributes.sort_by(|(emoji_a, a), (emoji_b, b)| {
+        compare_attributes(a, b).then_with(|| emoji_a.as_str().cmp(emoji_b.as_str()))
+    });
 
-    // Extract sorted emojis
-    // Extract sorted emojis
-    let results: Vec<Emoji> = emojis_attributes
-        .into_iter()
-        .map(|(emoji, _attributes)| emoji)
-        .cloned()
-        .collect();
-
-    debug!("Found {} best matching emojis", results.len());
-    results
+    emojis_attributes
 }
 
 /// Get the best attributes for the emoji based on its keywords matching against the input words
@@ -108,7 +199,7 @@ fn get_emoji_best_attributes(
     let jointed_keywords_set: HashSet<String> = jointed_keywords_array.iter().cloned().collect();
 
     // Get match counts
-    let attributes = get_num_matches(
+    let mut attributes = get_num_matches(
         input_words_array,
         stemmed_input_words_array,
         &jointed_keywords_array,
@@ -121,12 +212,47 @@ fn get_emoji_best_attributes(
         || attributes.num_prefix_word_matches > 0
         || attributes.num_prefix_stemmed_word_matches > 0
     {
+        attributes.matched_keyword = find_matched_keyword(
+            input_words_array,
+            stemmed_input_words_array,
+            keywords,
+            &processed_keywords,
+        );
         Some(attributes)
     } else {
         None
     }
 }
 
+/// Find the keyword with the most words matching an input word, the first one on ties
+fn find_matched_keyword(
+    input_words_array: &[String],
+    stemmed_input_words_array: &[String],
+    keywords: &[String],
+    processed_keywords: &[String],
+) -> Option<String> {
+    let mut best: Option<(usize, usize)> = None;
+
+    for (idx, keyword) in processed_keywords.iter().enumerate() {
+        let num_matched_words = input_words_array
+            .iter()
+            .zip(stemmed_input_words_array)
+            .filter(|(input_word, stemmed_input_word)| {
+                keyword.split(' ').any(|keyword_word| {
+                    keyword_word.starts_with(input_word.as_str())
+                        || keyword_word.starts_with(stemmed_input_word.as_str())
+                })
+            })
+            .count();
+
+        if num_matched_words > best.map_or(0, |(_, num)| num) {
+            best = Some((idx, num_matched_words));
+        }
+    }
+
+    best.map(|(idx, _)| keywords[idx].clone())
+}
+
 /// Calculate the number of different types of matches between input words and keywords
 fn get_num_matches(
     input_words_array: &[String],
@@ -180,6 +306,7 @@ fn get_num_matches(
         num_exact_stemmed_word_matches,
         num_prefix_word_matches,
         num_prefix_stemmed_word_matches,
+        matched_keyword: None,
     }
 }
 
@@ -0,0 +1,213 @@
+// src/search/compose.rs
+use emojis::common::EMOJIS;
+use emojis::emoji::{Emoji, SkinTone};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+/// Map from lowercase CLDR name to emoji, used to check that a composed sequence exists
+/// e.g. {"woman firefighter": "👩‍🚒", "family: man, woman, girl": "👨‍👩‍👧"}
+static EMOJI_BY_NAME: Lazy<HashMap<String, Emoji>> = Lazy::new(|| {
+    EMOJIS
+        .iter()
+        .map(|emoji| (emoji.name().to_lowercase(), emoji.clone()))
+        .collect()
+});
+
+/// Modifiers describing an emoji sequence, parsed from the input words
+#[derive(Debug, Default)]
+struct Components {
+    skin_tone: Option<SkinTone>,
+    /// Whether the skin tone was given without "skin" or "tone", e.g. "woman firefighter dark"
+    is_bare_skin_tone: bool,
+    hair: Option<&'static str>,
+    people: Vec<&'static str>,
+    is_family: bool,
+    role_words: Vec<String>,
+}
+
+/// Compose an emoji ZWJ sequence from an input describing it, e.g.
+/// "woman firefighter medium skin" -> 👩🏽‍🚒 or "family man woman girl" -> 👨‍👩‍👧
+///
+/// Only sequences known to `emojis::EMOJIS` are returned, optionally with a skin tone applied.
+pub fn compose_emoji_sequence(input_words: &str) -> Option<Emoji> {
+    let input_words_array: Vec<String> = input_words.split(' ').map(|s| s.to_string()).collect();
+    let components = parse_components(&input_words_array);
+    trace!("Parsed sequence components: {:?}", components);
+
+    // Nothing to compose without a person, hair or skin tone modifier
+    if components.people.is_empty() && components.hair.is_none() && components.skin_tone.is_none() {
+        return None;
+    }
+
+    let emoji = get_candidate_names(&components)
+        .into_iter()
+        .find_map(|name| EMOJI_BY_NAME.get(&name).cloned())?;
+
+    let emoji = match components.skin_tone {
+        Some(skin_tone) => match emoji.with_skin_tone(skin_tone) {
+            Some(toned) => toned.to_owned(),
+            // A bare "light" or "dark" of an emoji without skin tones describes something else
+            None if components.is_bare_skin_tone => return None,
+            None => emoji,
+        },
+        None => emoji,
+    };
+
+    debug!("Composed emoji {} for input: {}", emoji, input_words);
+    Some(emoji)
+}
+
+/// Parse a skin tone description, e.g. ["medium", "dark"] or ["dark", "skin", "tone"]
+///
+/// Returns the skin tone and the number of words it spans. Tone words only count when they
+/// are followed by "skin" or "tone", or when they are all the words.
+pub fn parse_skin_tone(words: &[String]) -> Option<(SkinTone, usize)> {
+    match parse_skin_tone_words(words)? {
+        (skin_tone, len, true) => Some((skin_tone, len)),
+        (skin_tone, len, false) if len == words.len() => Some((skin_tone, len)),
+        _ => None,
+    }
+}
+
+/// Parse tone words at the start of the words, optionally followed by "skin" or "tone"
+///
+/// Returns the skin tone, the number of words it spans and whether "skin" or "tone" was given.
+fn parse_skin_tone_words(words: &[String]) -> Option<(SkinTone, usize, bool)> {
+    let word = |idx: usize| words.get(idx).map(String::as_str);
+
+    let (skin_tone, mut len) = match (word(0)?, word(1)) {
+        ("medium", Some("light")) => (SkinTone::MediumLight, 2),
+        ("medium", Some("dark")) => (SkinTone::MediumDark, 2),
+        ("light", _) => (SkinTone::Light, 1),
+        ("medium", _) => (SkinTone::Medium, 1),
+        ("dark", _) => (SkinTone::Dark, 1),
+        _ => return None,
+    };
+
+    let mut has_skin_word = false;
+    while let Some("skin" | "tone" | "toned" | "skinned") = word(len) {
+        has_skin_word = true;
+        len += 1;
+    }
+
+    Some((skin_tone, len, has_skin_word))
+}
+
+/// Parse a hair style at the start of the words, e.g. ["red", "hair"] -> "red hair"
+fn parse_hair(words: &[String]) -> Option<(&'static str, usize)> {
+    let word = |idx: usize| words.get(idx).map(String::as_str);
+    let has_hair_word = matches!(word(1), Some("hair" | "haired"));
+
+    match word(0)? {
+        "redhead" | "ginger" => Some(("red hair", 1)),
+        "bald" => Some(("bald", 1)),
+        "red" if has_hair_word => Some(("red hair", 2)),
+        "white" if has_hair_word => Some(("white hair", 2)),
+        "curly" if has_hair_word => Some(("curly hair", 2)),
+        "curly" => Some(("curly hair", 1)),
+        "blond" | "blonde" if has_hair_word => Some(("blond hair", 2)),
+        "blond" | "blonde" => Some(("blond hair", 1)),
+        _ => None,
+    }
+}
+
+/// Split the input words into skin tone, hair, people and role components
+fn parse_components(words: &[String]) -> Components {
+    let mut components = Components::default();
+    let mut i = 0;
+
+    while i < words.len() {
+        // A tone word needs "skin" or "tone" after it, or a person or role before it when it
+        // ends the input, so "dark" in "night sky dark" isn't a skin tone
+        let has_subject = !components.people.is_empty() || !components.role_words.is_empty();
+        match parse_skin_tone_words(&words[i..]) {
+            Some((skin_tone, len, true)) => {
+                components.skin_tone = Some(skin_tone);
+                components.is_bare_skin_tone = false;
+                i += len;
+                continue;
+            }
+            Some((skin_tone, len, false)) if has_subject && i + len == words.len() => {
+                components.skin_tone = Some(skin_tone);
+                components.is_bare_skin_tone = true;
+                i += len;
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some((hair, len)) = parse_hair(&words[i..]) {
+            components.hair = Some(hair);
+            i += len;
+            continue;
+        }
+
+        match words[i].as_str() {
+            "family" => components.is_family = true,
+            "man" | "men" | "male" | "guy" => components.people.push("man"),
+            "woman" | "women" | "female" | "lady" => components.people.push("woman"),
+            "person" | "adult" => components.people.push("person"),
+            "girl" => components.people.push("girl"),
+            "boy" => components.people.push("boy"),
+            "child" | "kid" => components.people.push("child"),
+            "" => {}
+            word => components.role_words.push(word.to_string()),
+        }
+
+        i += 1;
+    }
+
+    components
+}
+
+/// Get the CLDR names the components could describe, most specific first
+fn get_candidate_names(components: &Components) -> Vec<String> {
+    let mut names = Vec::new();
+
+    if components.is_family {
+        if components.people.is_empty() {
+            names.push("family".to_string());
+        } else {
+            // Families use "adult" and "child" for gender neutral members
+            let members: Vec<&str> = components
+                .people
+                .iter()
+                .map(|&person| if person == "person" { "adult" } else { person })
+                .collect();
+            names.push(format!("family: {}", members.join(", ")));
+        }
+    }
+
+    // Outside of families, several people like "man woman" don't describe a single emoji
+    if !components.is_family && components.people.len() > 1 {
+        return names;
+    }
+
+    // Roles and hair styles are only composed for men, women and gender neutral persons
+    let gender = components
+        .people
+        .iter()
+        .copied()
+        .find(|&person| matches!(person, "man" | "woman" | "person"));
+
+    if let Some(hair) = components.hair {
+        names.push(format!("{}: {}", gender.unwrap_or("person"), hair));
+    }
+
+    if !components.role_words.is_empty() {
+        let role = components.role_words.join(" ");
+        match gender {
+            Some("person") | None => {
+                names.push(role.clone());
+                names.push(format!("person {}", role));
+            }
+            Some(gender) => names.push(format!("{} {}", gender, role)),
+        }
+    } else if components.hair.is_none() && !components.is_family && components.skin_tone.is_some() {
+        // A bare person with a skin tone, e.g. "woman dark skin"
+        names.extend(gender.map(|gender| gender.to_string()));
+    }
+
+    names
+}
@@ -0,0 +1,282 @@
+// src/search/emojify.rs
+use crate::constants::{EmojiData, Options};
+use crate::search::single_word::match_emojis_to_word;
+use crate::utils::nlp::parts_of_speech::is_function_word;
+use crate::utils::nlp::stemmer::stem_word;
+use crate::utils::preprocess::pre_process_string;
+use emojis::emoji::Emoji;
+use tracing::{debug, trace};
+
+/// Confidence of a suggestion coming from the custom most relevant emojis
+const CUSTOM_MOST_RELEVANT_CONFIDENCE: f32 = 1.0;
+
+/// Confidence of a suggestion coming from the built-in most relevant emojis
+const MOST_RELEVANT_CONFIDENCE: f32 = 0.9;
+
+/// Confidence of a suggestion coming from the custom emoji keywords
+const CUSTOM_KEYWORD_CONFIDENCE: f32 = 0.8;
+
+/// Confidence of the first glossary emoji, later ones are scaled down by their position
+const GLOSSARY_CONFIDENCE: f32 = 0.7;
+
+/// Factor applied to the confidence of suggestions found through the stemmed word
+const STEMMED_CONFIDENCE_FACTOR: f32 = 0.8;
+
+/// Confidence of the first emoji of a single word search, later ones are scaled down
+const SEARCH_CONFIDENCE: f32 = 0.5;
+
+/// An emoji suggested for a word, with how confident we are that it fits
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EmojiSuggestion {
+    pub emoji: Emoji,
+    /// Between 0 and 1, higher is better
+    pub confidence: f32,
+}
+
+/// A word of the input text together with the emojis suggested for it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct EmojifyToken {
+    /// Byte offset where the word starts in the input
+    pub start: u32,
+    /// Byte offset where the word ends in the input (exclusive)
+    pub end: u32,
+    /// The word as it appears in the input, e.g. "Cats"
+    pub word: String,
+    /// Suggested emojis, most confident first
+    pub suggestions: Vec<EmojiSuggestion>,
+}
+
+/// How suggested emojis are inserted into the text when rendering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Enum))]
+pub enum EmojifyMode {
+    /// Insert the emoji after the word, e.g. "I love ❤️ pizza 🍕"
+    #[default]
+    Append,
+    /// Replace the word with the emoji, e.g. "I ❤️ 🍕"
+    Replace,
+    /// Add all emojis at the end of the text, e.g. "I love pizza ❤️🍕"
+    Suffix,
+}
+
+/// Find emoji suggestions for every content word of a free text, e.g. "I love pizza and cats"
+///
+/// Function words (pronouns, prepositions, articles, ...) are skipped and only words with at
+/// least one suggestion are returned, in the order they appear in the input.
+pub async fn emojify_words(
+    input: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+    max_suggestions: usize,
+) -> Vec<EmojifyToken> {
+    debug!("Emojifying text: {}", input);
+
+    let spans = split_word_spans(input);
+    let processed_words: Vec<String> = spans
+        .iter()
+        .map(|&(start, end)| pre_process_string(&input[start..end]))
+        .collect();
+
+    let mut tokens = Vec::new();
+
+    for (idx, &(start, end)) in spans.iter().enumerate() {
+        let word = &processed_words[idx];
+
+        if word.is_empty() || is_function_word(&processed_words, idx) {
+            trace!("Skipping function word: {}", word);
+            continue;
+        }
+
+        let mut suggestions = suggest_emojis_for_keyword(word, emoji_data, options).await;
+        if suggestions.is_empty() {
+            continue;
+        }
+
+        suggestions.truncate(max_suggestions);
+
+        tokens.push(EmojifyToken {
+            start: start as u32,
+            end: end as u32,
+            word: input[start..end].to_string(),
+            suggestions,
+        });
+    }
+
+    debug!("Found suggestions for {} words", tokens.len());
+    tokens
+}
+
+/// Render the input text with the most confident suggestion of each token inserted
+pub fn render_emojified(input: &str, tokens: &[EmojifyToken], mode: EmojifyMode) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut suffix = String::new();
+    let mut last_end = 0;
+
+    for token in tokens {
+        let Some(best) = token.suggestions.first() else {
+            continue;
+        };
+        let (start, end) = (token.start as usize, token.end as usize);
+
+        match mode {
+            EmojifyMode::Append => {
+                result.push_str(&input[last_end..end]);
+                result.push(' ');
+                result.push_str(&best.emoji.to_string());
+            }
+            EmojifyMode::Replace => {
+                result.push_str(&input[last_end..start]);
+                result.push_str(&best.emoji.to_string());
+            }
+            EmojifyMode::Suffix => {
+                result.push_str(&input[last_end..end]);
+                suffix.push_str(&best.emoji.to_string());
+            }
+        }
+
+        last_end = end;
+    }
+
+    result.push_str(&input[last_end..]);
+
+    if !suffix.is_empty() {
+        result.push(' ');
+        result.push_str(&suffix);
+    }
+
+    result
+}
+
+/// Collect the suggestions for a pre-processed word or phrase, most confident first
+///
+/// Falls back to the stemmed words when the word or phrase itself has no suggestions, e.g.
+/// "running shoes" -> "run shoe", and then to a single word search for a single word.
+pub(super) async fn suggest_emojis_for_keyword(
+    keyword: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<EmojiSuggestion> {
+    let mut suggestions = get_word_suggestions(keyword, emoji_data, options, 1.0);
+
+    // If no results, try with the stemmed words
+    if suggestions.is_empty() {
+        let stemmed_keyword = keyword
+            .split(' ')
+            .map(stem_word)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if stemmed_keyword != keyword {
+            suggestions = get_word_suggestions(
+                &stemmed_keyword,
+                emoji_data,
+                options,
+                STEMMED_CONFIDENCE_FACTOR,
+            );
+        }
+    }
+
+    // Still no results, search the word like the search bar would
+    if suggestions.is_empty() && !keyword.contains(' ') {
+        trace!("No suggestions for {}, searching it", keyword);
+        suggestions = get_search_suggestions(keyword, emoji_data, options).await;
+    }
+
+    suggestions
+}
+
+/// Get suggestions for a word from a single word search, most confident first
+async fn get_search_suggestions(
+    word: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<EmojiSuggestion> {
+    match_emojis_to_word(word, emoji_data, options)
+        .await
+        .into_iter()
+        .enumerate()
+        .map(|(i, emoji)| EmojiSuggestion {
+            emoji,
+            confidence: SEARCH_CONFIDENCE / (i + 1) as f32,
+        })
+        .collect()
+}
+
+/// Split the input into words, returning the byte span of each word without surrounding
+/// punctuation, e.g. "cats," -> "cats"
+fn split_word_spans(input: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut offset = 0;
+
+    for chunk in input.split(char::is_whitespace) {
+        let trimmed_start = chunk.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let trimmed = trimmed_start.trim_end_matches(|c: char| !c.is_alphanumeric());
+
+        if !trimmed.is_empty() {
+            let start = offset + (chunk.len() - trimmed_start.len());
+            spans.push((start, start + trimmed.len()));
+        }
+
+        // Skip past the chunk and the whitespace character that ended it
+        offset += chunk.len();
+        offset += input[offset..].chars().next().map_or(0, char::len_utf8);
+    }
+
+    spans
+}
+
+/// Collect the suggestions for a pre-processed word, most confident first
+fn get_word_suggestions(
+    word: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+    confidence_factor: f32,
+) -> Vec<EmojiSuggestion> {
+    let mut suggestions: Vec<EmojiSuggestion> = Vec::new();
+
+    let mut add_suggestion = |emoji: &Emoji, confidence: f32| {
+        let confidence = confidence * confidence_factor;
+        match suggestions.iter_mut().find(|s| &s.emoji == emoji) {
+            Some(existing) => existing.confidence = existing.confidence.max(confidence),
+            None => suggestions.push(EmojiSuggestion {
+                emoji: emoji.clone(),
+                confidence,
+            }),
+        }
+    };
+
+    if let Some(emoji) = options
+        .custom_keyword_most_relevant_emoji
+        .as_ref()
+        .and_then(|custom| custom.get(word))
+    {
+        add_suggestion(emoji, CUSTOM_MOST_RELEVANT_CONFIDENCE);
+    }
+
+    if let Some(emoji) = emoji_data.keyword_most_relevant_emoji.get(word) {
+        add_suggestion(emoji, MOST_RELEVANT_CONFIDENCE);
+    }
+
+    if let Some(custom_emoji_keywords) = options.custom_emoji_keywords.as_ref() {
+        for (emoji, keywords) in custom_emoji_keywords {
+            if keywords.iter().any(|k| pre_process_string(k) == word) {
+                add_suggestion(emoji, CUSTOM_KEYWORD_CONFIDENCE);
+            }
+        }
+    }
+
+    // Emojis removed from the data stay in the curated glossary
+    if let Some(emojis) = emoji_data.emoji_glossary.get(word) {
+        let emojis = emojis
+            .iter()
+            .filter(|emoji| emoji_data.emoji_set.contains(*emoji));
+        for (i, emoji) in emojis.enumerate() {
+            add_suggestion(emoji, GLOSSARY_CONFIDENCE / (i + 1) as f32);
+        }
+    }
+
+    // Stable sort keeps the source priority for equal confidences
+    suggestions.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    suggestions
+}
@@ -0,0 +1,103 @@
+// src/search/flags.rs
+use crate::utils::preprocess::{fold_diacritics, pre_process_string};
+use emojis::emoji::Emoji;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{error, info, trace, warn};
+
+/// Offset from an ASCII uppercase letter to its regional indicator symbol
+const REGIONAL_INDICATOR_OFFSET: u32 = 0x1F1E6 - 'A' as u32;
+
+/// A country with its ISO 3166 codes, names and demonyms
+#[derive(Debug, Deserialize)]
+struct Country {
+    /// e.g. "DE"
+    alpha2: String,
+    /// e.g. "DEU"
+    alpha3: String,
+    /// Official, common and alias names, e.g. ["Germany", "Federal Republic of Germany"]
+    names: Vec<String>,
+    /// e.g. ["german"]
+    demonyms: Vec<String>,
+}
+
+/// Country flags by normalized code, name and demonym
+struct FlagLookup {
+    /// e.g. {"de": 🇩🇪, "deu": 🇩🇪, "germany": 🇩🇪}
+    flags: HashMap<String, Emoji>,
+}
+
+static FLAG_LOOKUP: Lazy<FlagLookup> = Lazy::new(|| {
+    let mut flag_lookup = FlagLookup {
+        flags: HashMap::new(),
+    };
+
+    let countries: Vec<Country> =
+        match serde_json::from_str(include_str!("../../data/country-flags.json")) {
+            Ok(countries) => countries,
+            Err(e) => {
+                error!("Failed to parse country flags: {}", e);
+                return flag_lookup;
+            }
+        };
+
+    for country in &countries {
+        let Some(flag) = get_regional_indicator_flag(&country.alpha2) else {
+            warn!("Could not find flag for country code: {}", country.alpha2);
+            continue;
+        };
+
+        // Names and demonyms take precedence over codes of other countries
+        for name in country.names.iter().chain(&country.demonyms) {
+            flag_lookup
+                .flags
+                .entry(normalize_country_name(name))
+                .or_insert_with(|| flag.clone());
+        }
+    }
+
+    for country in &countries {
+        if let Some(flag) = get_regional_indicator_flag(&country.alpha2) {
+            for code in [&country.alpha2, &country.alpha3] {
+                flag_lookup
+                    .flags
+                    .entry(code.to_lowercase())
+                    .or_insert_with(|| flag.clone());
+            }
+        }
+    }
+
+    info!("Loaded country flags: {} entries", flag_lookup.flags.len());
+    flag_lookup
+});
+
+/// Resolve an input to a country flag by ISO 3166 alpha-2 or alpha-3 code, country name or
+/// demonym, e.g. "de", "deu", "germany", "german" or "côte d'ivoire"
+pub fn resolve_flag(input: &str) -> Option<Emoji> {
+    let key = normalize_country_name(input);
+    let flag = FLAG_LOOKUP.flags.get(&key).cloned();
+    trace!("Resolved flag for {}: {:?}", input, flag);
+    flag
+}
+
+/// Normalize a country name for lookup, e.g. "Congo, The Democratic Republic of the" ->
+/// "congo the democratic republic of the"
+fn normalize_country_name(name: &str) -> String {
+    fold_diacritics(&pre_process_string(name))
+        .replace(['\'', '’'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Get the regional indicator flag of an alpha-2 code, e.g. "DE" -> 🇩🇪
+fn get_regional_indicator_flag(alpha2: &str) -> Option<Emoji> {
+    let flag: String = alpha2
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .filter_map(|c| char::from_u32(c.to_ascii_uppercase() as u32 + REGIONAL_INDICATOR_OFFSET))
+        .collect();
+
+    emojis::get(&flag)
+}
@@ -0,0 +1,62 @@
+// src/search/hit.rs
+use crate::custom_emoji::SearchResult;
+use emojis::emoji::Emoji;
+
+/// A search result with the keyword that matched the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct SearchHit {
+    pub emoji: Emoji,
+    /// The keyword of the emoji that matched the input, e.g. "dog face" for 🐶 and "dog",
+    /// `None` for an emoji the input describes without a keyword, like a flag or a composed
+    /// sequence
+    pub matched_keyword: Option<String>,
+}
+
+impl From<Emoji> for SearchHit {
+    fn from(emoji: Emoji) -> Self {
+        SearchHit {
+            emoji,
+            matched_keyword: None,
+        }
+    }
+}
+
+/// A ranked search result, among which an emoji described by the input is placed
+pub(crate) trait RankedItem: From<Emoji> {
+    /// Whether the item is the emoji
+    fn is_emoji(&self, emoji: &Emoji) -> bool;
+
+    /// The item of a hit found by another search, e.g. for a phrase inside the input
+    fn from_hit(hit: SearchHit) -> Self;
+}
+
+impl RankedItem for Emoji {
+    fn is_emoji(&self, emoji: &Emoji) -> bool {
+        self == emoji
+    }
+
+    fn from_hit(hit: SearchHit) -> Self {
+        hit.emoji
+    }
+}
+
+impl RankedItem for SearchHit {
+    fn is_emoji(&self, emoji: &Emoji) -> bool {
+        &self.emoji == emoji
+    }
+
+    fn from_hit(hit: SearchHit) -> Self {
+        hit
+    }
+}
+
+impl RankedItem for SearchResult {
+    fn is_emoji(&self, emoji: &Emoji) -> bool {
+        matches!(self, SearchResult::Unicode { emoji: e } if e == emoji)
+    }
+
+    fn from_hit(hit: SearchHit) -> Self {
+        SearchResult::from(hit.emoji)
+    }
+}
@@ -1,8 +1,20 @@
 // src/search/mod.rs
 mod best_matching;
+mod compose;
+mod emojify;
+mod flags;
+mod hit;
 mod multiple_words;
 mod single_word;
+mod suggest;
 
-pub use best_matching::match_emoji_to_words;
+pub use best_matching::{match_emoji_to_words, match_hits_to_words, match_items_to_words};
+pub use compose::{compose_emoji_sequence, parse_skin_tone};
+pub use emojify::{emojify_words, render_emojified, EmojiSuggestion, EmojifyMode, EmojifyToken};
+pub use flags::resolve_flag;
+pub(crate) use hit::RankedItem;
+pub use hit::SearchHit;
 pub use multiple_words::match_emojis_to_words_raw;
-pub use single_word::match_emojis_to_word;
+pub(crate) use multiple_words::match_phrase_segments;
+pub use single_word::{match_emojis_to_word, match_hits_to_word, match_items_to_word};
+pub use suggest::{suggest_emojis_for_message, MessageConcept, MessageSuggestions};
@@ -1,9 +1,14 @@
 // src/search/multiple_words.rs
-use crate::constants::{EmojiData, Options};
+use crate::constants::{EmojiData, Options, PhraseEmojis};
+use crate::search::hit::SearchHit;
+use crate::search::single_word::match_hits_to_word;
+use crate::utils::nlp::parts_of_speech::is_function_word;
 use crate::utils::preprocess::pre_process_string;
+use crate::utils::task::spawn;
 use emojis::emoji::Emoji;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::{debug, trace};
 
 /// Attributes for ranking emojis in multiple words search
@@ -18,6 +23,24 @@ struct Attributes {
     num_words_in_multiple_words_keyword: usize,
 }
 
+/// A part of a multiple words input, matched on its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// A multiple words keyword inside the input, e.g. "red heart" in "red heart balloon"
+    Phrase(String),
+    /// A word left over between phrases, e.g. "balloon"
+    Word(String),
+}
+
+/// Emojis of the phrases inside a multiple words input and of the words left over
+pub(crate) struct PhraseMatches {
+    /// Emojis with a keyword containing the whole input in order, ranked above the phrases
+    pub in_order_emojis: HashSet<Emoji>,
+    /// The best emoji of every segment first, in input order, e.g. ❤️ 🎈 for
+    /// "red heart balloon"
+    pub hits: Vec<SearchHit>,
+}
+
 /// Search emojis for an input with multiple words, e.g. "smiling face"
 pub async fn match_emojis_to_words_raw(
     input_words: &str,
@@ -26,18 +49,20 @@ pub async fn match_emojis_to_words_raw(
 ) -> Vec<Emoji> {
     debug!("Searching emojis for multiple words input: {}", input_words);
 
-    // Create owned copies of the option values to avoid borrowing issues
+    // Create owned copies of the option values to avoid borrowing issues, shared by all tasks
     let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
-    let custom_keyword_most_relevant_emoji = options
-        .custom_keyword_most_relevant_emoji
-        .clone()
-        .unwrap_or_default();
+    let custom_keyword_most_relevant_emoji = Arc::new(
+        options
+            .custom_keyword_most_relevant_emoji
+            .clone()
+            .unwrap_or_default(),
+    );
 
     let input_words_array: Vec<String> = input_words.split(' ').map(|s| s.to_string()).collect();
 
     let mut emojis_attributes: Vec<(Emoji, Attributes)> = Vec::new();
 
-    // Use tokio tasks to process emojis in parallel
+    // Use tasks to process emojis in parallel
     let mut handles = Vec::new();
 
     for (emoji, keywords) in emoji_data.emoji_keywords.iter() {
@@ -48,7 +73,7 @@ pub async fn match_emojis_to_words_raw(
         let input_words = input_words.to_string();
         let input_words_array = input_words_array.clone();
 
-        let handle = tokio::spawn(async move {
+        let handle = spawn(move || {
             let all_keywords = if let Some(custom_kw) = custom_keywords {
                 let mut combined = keywords;
                 combined.extend(custom_kw);
@@ -78,15 +103,51 @@ pub async fn match_emojis_to_words_raw(
         }
     }
 
-    // Sort emojis by attributes
-    emojis_attributes.sort_by(|(_, a), (_, b)| compare_attributes(a, b));
+    // Sort emojis by attributes, ties in emoji order so results don't depend on map order
+    emojis_attributes.sort_by(|(emoji_a, a), (emoji_b, b)| {
+        compare_attributes(a, b).then_with(|| emoji_a.as_str().cmp(emoji_b.as_str()))
+    });
+
+    let num_keyword_matches = emojis_attributes
+        .iter()
+        .take_while(|(_, attributes)| attributes.is_multiple_words_keyword_match)
+        .count();
 
     // Extract sorted emojis
-    let results: Vec<Emoji> = emojis_attributes
+    let mut results: Vec<Emoji> = emojis_attributes
         .into_iter()
         .map(|(emoji, _attributes)| emoji)
         .collect();
 
+    // Phrases inside the input, e.g. "red heart" in "red heart balloon", rank below
+    // keywords matching the whole input and above bag-of-words matches
+    let custom_phrase_emojis = get_custom_phrase_emojis(options);
+    let segments = segment_input_words(
+        &input_words_array,
+        &emoji_data.phrase_emojis,
+        &custom_phrase_emojis,
+    );
+    if segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Phrase(_)))
+    {
+        trace!("Input segments: {:?}", segments);
+        let segments_hits =
+            match_segments(&segments, &custom_phrase_emojis, emoji_data, options).await;
+
+        let bag_of_words_emojis = results.split_off(num_keyword_matches);
+        let mut seen_emojis: HashSet<Emoji> = results.iter().cloned().collect();
+        for emoji in merge_round_robin(segments_hits)
+            .into_iter()
+            .map(|hit| hit.emoji)
+            .chain(bag_of_words_emojis)
+        {
+            if seen_emojis.insert(emoji.clone()) {
+                results.push(emoji);
+            }
+        }
+    }
+
     debug!(
         "Found {} matching emojis for multiple words input",
         results.len()
@@ -94,6 +155,59 @@ pub async fn match_emojis_to_words_raw(
     results
 }
 
+/// Match the phrases inside a multiple words input, e.g. "red heart" in "red heart balloon",
+/// and the content words left over
+///
+/// # Returns
+/// The matches, or `None` when the input contains no phrase
+pub(crate) async fn match_phrase_segments(
+    input_words: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Option<PhraseMatches> {
+    let input_words_array: Vec<String> = input_words.split(' ').map(|s| s.to_string()).collect();
+
+    let custom_phrase_emojis = get_custom_phrase_emojis(options);
+    let segments = segment_input_words(
+        &input_words_array,
+        &emoji_data.phrase_emojis,
+        &custom_phrase_emojis,
+    );
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Phrase(_)))
+    {
+        return None;
+    }
+
+    trace!("Input segments: {:?}", segments);
+    let segments_hits = match_segments(&segments, &custom_phrase_emojis, emoji_data, options).await;
+
+    let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
+    let in_order_emojis = emoji_data
+        .emoji_keywords
+        .iter()
+        .filter(|(emoji, keywords)| {
+            keywords
+                .iter()
+                .chain(custom_emoji_keywords.get(*emoji).into_iter().flatten())
+                .any(|keyword| is_in_order_match(&pre_process_string(keyword), input_words))
+        })
+        .map(|(emoji, _)| emoji.clone())
+        .collect();
+
+    Some(PhraseMatches {
+        in_order_emojis,
+        hits: merge_round_robin(segments_hits),
+    })
+}
+
+/// Whether a pre-processed keyword contains the input words in order, e.g. "smiling face
+/// with halo" for "face with halo"
+fn is_in_order_match(keyword: &str, input_words: &str) -> bool {
+    keyword.starts_with(input_words) || keyword.contains(&format!(" {}", input_words))
+}
+
 /// Get best attributes for emoji based on its keywords matching against input words
 fn get_emoji_best_attributes(
     input_words: &str,
@@ -124,7 +238,7 @@ fn get_emoji_best_attributes(
         // Check for exact in-order match
         if keyword == input_words {
             let is_custom_most_relevant_emoji =
-                custom_keyword_most_relevant_emoji.get(&keyword) == Some(&emoji);
+                custom_keyword_most_relevant_emoji.get(&keyword) == Some(emoji);
 
             let attributes = Attributes {
                 is_multiple_words_keyword_match: true,
@@ -144,13 +258,12 @@ fn get_emoji_best_attributes(
             }
         }
         // Check for partial in-order match
-        else if keyword.starts_with(input_words) || keyword.contains(&format!(" {}", input_words))
-        {
+        else if is_in_order_match(&keyword, input_words) {
             let keyword_words_array: Vec<String> =
                 keyword.split(' ').map(|s| s.to_string()).collect();
 
             let is_custom_most_relevant_emoji =
-                custom_keyword_most_relevant_emoji.get(&keyword) == Some(&emoji);
+                custom_keyword_most_relevant_emoji.get(&keyword) == Some(emoji);
 
             let attributes = Attributes {
                 is_multiple_words_keyword_match: true,
@@ -236,6 +349,165 @@ fn get_emoji_best_attributes(
     emoji_best_attributes
 }
 
+/// Map the multiple words custom keywords of the options to their emojis
+fn get_custom_phrase_emojis(options: &Options) -> PhraseEmojis {
+    let mut custom_phrase_emojis: PhraseEmojis = HashMap::new();
+
+    for (emoji, keywords) in options.custom_emoji_keywords.iter().flatten() {
+        for keyword in keywords {
+            let phrase = pre_process_string(keyword).trim().to_string();
+            if phrase.contains(' ') {
+                custom_phrase_emojis
+                    .entry(phrase)
+                    .or_default()
+                    .push(emoji.clone());
+            }
+        }
+    }
+
+    custom_phrase_emojis
+}
+
+/// Segment input words into the longest multiple words keywords they contain, and the
+/// content words left over, in input order
+///
+/// e.g. "red heart balloon" gives [Phrase("red heart"), Word("balloon")].
+fn segment_input_words(
+    input_words_array: &[String],
+    phrase_emojis: &PhraseEmojis,
+    custom_phrase_emojis: &PhraseEmojis,
+) -> Vec<Segment> {
+    let is_phrase = |phrase: &str| {
+        phrase_emojis.contains_key(phrase) || custom_phrase_emojis.contains_key(phrase)
+    };
+
+    // Length of the phrase starting at each word, 0 for words outside phrases
+    let mut phrase_lens = vec![0; input_words_array.len()];
+    let mut is_phrase_word = vec![false; input_words_array.len()];
+
+    // Longest n-grams first, the whole input is already checked as a keyword match
+    for n in (2..input_words_array.len()).rev() {
+        let mut start = 0;
+
+        while start + n <= input_words_array.len() {
+            let is_free = !is_phrase_word[start..start + n].contains(&true);
+
+            if is_free && is_phrase(&input_words_array[start..start + n].join(" ")) {
+                is_phrase_word[start..start + n].fill(true);
+                phrase_lens[start] = n;
+                start += n;
+            } else {
+                start += 1;
+            }
+        }
+    }
+
+    let mut segments = Vec::new();
+    let mut idx = 0;
+    while idx < input_words_array.len() {
+        match phrase_lens[idx] {
+            0 => {
+                // Function words on their own match nearly anything, e.g. "with"
+                if !is_function_word(input_words_array, idx) {
+                    segments.push(Segment::Word(input_words_array[idx].clone()));
+                }
+                idx += 1;
+            }
+            n => {
+                segments.push(Segment::Phrase(input_words_array[idx..idx + n].join(" ")));
+                idx += n;
+            }
+        }
+    }
+
+    segments
+}
+
+/// Match every segment of the input on its own
+///
+/// The emojis of a phrase are ranked by the custom most relevant emoji, the most relevant
+/// emoji and the phrase being the emoji name, a word is searched like a single word input.
+async fn match_segments(
+    segments: &[Segment],
+    custom_phrase_emojis: &PhraseEmojis,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<Vec<SearchHit>> {
+    let mut segments_hits = Vec::with_capacity(segments.len());
+
+    for segment in segments {
+        let hits = match segment {
+            Segment::Phrase(phrase) => {
+                let mut seen_emojis: HashSet<&Emoji> = HashSet::new();
+                let mut emojis: Vec<Emoji> = custom_phrase_emojis
+                    .get(phrase)
+                    .into_iter()
+                    .chain(emoji_data.phrase_emojis.get(phrase))
+                    .flatten()
+                    .filter(|emoji| seen_emojis.insert(emoji))
+                    .cloned()
+                    .collect();
+
+                let custom_most_relevant_emoji = options
+                    .custom_keyword_most_relevant_emoji
+                    .as_ref()
+                    .and_then(|map| map.get(phrase));
+                let most_relevant_emoji = emoji_data.keyword_most_relevant_emoji.get(phrase);
+                let is_emoji_name = |emoji: &Emoji| {
+                    emoji_data
+                        .emoji_keywords
+                        .get(emoji)
+                        .and_then(|keywords| keywords.first())
+                        .is_some_and(|name| pre_process_string(name) == *phrase)
+                };
+
+                // Stable sort, emojis of a phrase are in emoji order
+                emojis.sort_by_key(|emoji| {
+                    (
+                        custom_most_relevant_emoji != Some(emoji),
+                        most_relevant_emoji != Some(emoji),
+                        !is_emoji_name(emoji),
+                    )
+                });
+                emojis
+                    .into_iter()
+                    .map(|emoji| SearchHit {
+                        emoji,
+                        matched_keyword: Some(phrase.clone()),
+                    })
+                    .collect()
+            }
+            Segment::Word(word) => match_hits_to_word(word, emoji_data, options).await,
+        };
+
+        segments_hits.push(hits);
+    }
+
+    segments_hits
+}
+
+/// Interleave the hits of the segments, the best hit of every segment first, without
+/// repeating an emoji
+///
+/// e.g. [[❤️, 💗], [🎈, 🎉]] gives [❤️, 🎈, 💗, 🎉].
+fn merge_round_robin(segments_hits: Vec<Vec<SearchHit>>) -> Vec<SearchHit> {
+    let max_len = segments_hits.iter().map(Vec::len).max().unwrap_or(0);
+    let mut seen_emojis: HashSet<Emoji> = HashSet::new();
+    let mut merged = Vec::new();
+
+    for idx in 0..max_len {
+        for hits in &segments_hits {
+            if let Some(hit) = hits.get(idx) {
+                if seen_emojis.insert(hit.emoji.clone()) {
+                    merged.push(hit.clone());
+                }
+            }
+        }
+    }
+
+    merged
+}
+
 /// Calculate the number of exact and prefix matches between input words and keywords
 fn get_num_matches(input_words_array: &[String], keywords_array: &[String]) -> (usize, usize) {
     let mut num_exact_matches = 0;
@@ -1,9 +1,15 @@
 // src/search/single_word.rs
 use crate::constants::{EmojiData, Options};
+use crate::custom_emoji::{CustomEmoji, SearchResult};
+use crate::search::flags::resolve_flag;
+use crate::search::hit::{RankedItem, SearchHit};
 use crate::utils::preprocess::pre_process_string;
+use crate::utils::task::spawn;
 use emojis::emoji::Emoji;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
 use tracing::{debug, trace};
 
 /// Attributes for ranking emojis in single word search
@@ -15,6 +21,8 @@ struct Attributes {
     is_emoji_name: bool,
     is_single_word: bool,
     match_word: String,
+    /// The keyword the match word is from, as written in the data
+    matched_keyword: String,
     prefix_match_recently_searched_inputs_idx: Option<u32>,
     prefix_match_top_1000_words_idx: Option<u32>,
 }
@@ -25,33 +33,138 @@ pub async fn match_emojis_to_word(
     emoji_data: &EmojiData,
     options: &Options,
 ) -> Vec<Emoji> {
+    match_hits_to_word(input_word, emoji_data, options)
+        .await
+        .into_iter()
+        .map(|hit| hit.emoji)
+        .collect()
+}
+
+/// Search emojis for a single word input with the keywords they matched, e.g. "dog"
+pub async fn match_hits_to_word(
+    input_word: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<SearchHit> {
     debug!("Searching emojis for single word input: {}", input_word);
 
-    // Create owned copies of the option values to avoid borrowing issues
-    let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
-    let custom_keyword_most_relevant_emoji = options
-        .custom_keyword_most_relevant_emoji
-        .clone()
-        .unwrap_or_default();
-    let recently_searched_inputs = options.recently_searched_inputs.clone().unwrap_or_default();
-
-    // Create map from words to recently searched indices
-    let word_to_recently_searched_inputs_idx: Option<HashMap<String, usize>> =
-        if !recently_searched_inputs.is_empty() {
-            Some(
-                recently_searched_inputs
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, input)| (input.clone(), idx))
-                    .collect(),
-            )
-        } else {
-            None
+    let word_to_recently_searched_inputs_idx = get_word_to_recently_searched_inputs_idx(options);
+    let emojis_attributes = rank_emojis_for_word(
+        input_word,
+        emoji_data,
+        options,
+        word_to_recently_searched_inputs_idx,
+    )
+    .await
+    .into_iter()
+    .map(|(emoji, attributes)| {
+        let hit = SearchHit {
+            emoji,
+            matched_keyword: Some(attributes.matched_keyword.clone()),
         };
+        (hit, attributes)
+    })
+    .collect();
+
+    let results = with_flag_ranked(input_word, emojis_attributes);
+
+    debug!(
+        "Found {} matching emojis for single word input",
+        results.len()
+    );
+    results
+}
+
+/// Search emojis and custom emojis for a single word input, e.g. "parrot"
+///
+/// Custom emojis are ranked by the same attributes as the Unicode emojis, and come after
+/// the Unicode emojis they tie with.
+pub async fn match_items_to_word(
+    input_word: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<SearchResult> {
+    debug!("Searching items for single word input: {}", input_word);
+
+    let word_to_recently_searched_inputs_idx = get_word_to_recently_searched_inputs_idx(options);
+
+    // Custom emoji sets are small, no need for tasks
+    let custom_emojis_attributes = rank_custom_emojis_for_word(
+        input_word,
+        emoji_data,
+        word_to_recently_searched_inputs_idx.as_deref(),
+    );
+
+    let mut items_attributes: Vec<(SearchResult, Attributes)> = rank_emojis_for_word(
+        input_word,
+        emoji_data,
+        options,
+        word_to_recently_searched_inputs_idx,
+    )
+    .await
+    .into_iter()
+    .map(|(emoji, attributes)| (SearchResult::from(emoji), attributes))
+    .collect();
+
+    items_attributes.extend(
+        custom_emojis_attributes
+            .into_iter()
+            .map(|(custom_emoji, attributes)| (SearchResult::from(custom_emoji), attributes)),
+    );
+
+    // Stable sort, so custom emojis stay after the Unicode emojis they tie with
+    items_attributes.sort_by(|(_, a), (_, b)| compare_attributes(a, b));
+
+    let results = with_flag_ranked(input_word, items_attributes);
+
+    debug!(
+        "Found {} matching items for single word input",
+        results.len()
+    );
+    results
+}
+
+/// Create map from words to recently searched indices
+fn get_word_to_recently_searched_inputs_idx(
+    options: &Options,
+) -> Option<Arc<HashMap<String, usize>>> {
+    let recently_searched_inputs = options
+        .recently_searched_inputs
+        .as_deref()
+        .unwrap_or_default();
+
+    if !recently_searched_inputs.is_empty() {
+        Some(Arc::new(
+            recently_searched_inputs
+                .iter()
+                .enumerate()
+                .map(|(idx, input)| (input.clone(), idx))
+                .collect(),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Get the emojis matching a single word input with their attributes, sorted by attributes
+async fn rank_emojis_for_word(
+    input_word: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+    word_to_recently_searched_inputs_idx: Option<Arc<HashMap<String, usize>>>,
+) -> Vec<(Emoji, Attributes)> {
+    // Create owned copies of the option values to avoid borrowing issues, shared by all tasks
+    let custom_emoji_keywords = options.custom_emoji_keywords.clone().unwrap_or_default();
+    let custom_keyword_most_relevant_emoji = Arc::new(
+        options
+            .custom_keyword_most_relevant_emoji
+            .clone()
+            .unwrap_or_default(),
+    );
 
     let mut emojis_attributes: Vec<(Emoji, Attributes)> = Vec::new();
 
-    // Use tokio tasks to process emojis in parallel
+    // Use tasks to process emojis in parallel
     let mut handles = Vec::new();
 
     for (emoji, keywords) in emoji_data.emoji_keywords.iter() {
@@ -64,7 +177,7 @@ pub async fn match_emojis_to_word(
         let word_to_top_1000_words_idx = emoji_data.word_to_top_1000_words_idx.clone();
         let input_word = input_word.to_string();
 
-        let handle = tokio::spawn(async move {
+        let handle = spawn(move || {
             let all_keywords = if let Some(custom_kw) = custom_keywords {
                 let mut combined = keywords;
                 combined.extend(custom_kw);
@@ -79,7 +192,7 @@ pub async fn match_emojis_to_word(
                 &all_keywords,
                 &custom_keyword_most_relevant_emoji,
                 &keyword_most_relevant_emoji,
-                word_to_recently_searched_inputs_idx.as_ref(),
+                word_to_recently_searched_inputs_idx.as_deref(),
                 &word_to_top_1000_words_idx,
             );
 
@@ -96,29 +209,92 @@ pub async fn match_emojis_to_word(
         }
     }
 
-    // Sort emojis by attributes
-    emojis_attributes.sort_by(|(_, a), (_, b)| compare_attributes(a, b));
+    // Sort emojis by attributes, ties in emoji order so results don't depend on map order
+    emojis_attributes.sort_by(|(emoji_a, a), (emoji_b, b)| {
+        compare_attributes(a, b).then_with(|| emoji_a.as_str().cmp(emoji_b.as_str()))
+    });
+
+    emojis_attributes
+}
+
+/// Get the custom emojis matching a single word input with their attributes
+///
+/// Custom emojis are never the most relevant emoji of a keyword, the most relevant emoji
+/// maps only hold Unicode emojis.
+fn rank_custom_emojis_for_word(
+    input_word: &str,
+    emoji_data: &EmojiData,
+    word_to_recently_searched_inputs_idx: Option<&HashMap<String, usize>>,
+) -> Vec<(CustomEmoji, Attributes)> {
+    let no_most_relevant_emoji: HashMap<String, CustomEmoji> = HashMap::new();
 
-    // Extract sorted emojis
-    let results: Vec<Emoji> = emojis_attributes
+    emoji_data
+        .custom_emojis
+        .candidates(&[input_word])
         .into_iter()
-        .map(|(emoji, _attributes)| emoji)
+        .filter_map(|(custom_emoji, keywords)| {
+            get_emoji_best_attributes(
+                input_word,
+                custom_emoji,
+                keywords,
+                &no_most_relevant_emoji,
+                &no_most_relevant_emoji,
+                word_to_recently_searched_inputs_idx,
+                &emoji_data.word_to_top_1000_words_idx,
+            )
+            .map(|attrs| (custom_emoji.clone(), attrs))
+        })
+        .collect()
+}
+
+/// Extract the items sorted by attributes, with the flag of a country name, demonym or
+/// code, e.g. "germany" or "de" -> 🇩🇪
+///
+/// The flag ranks right after the exact keyword matches, above the prefix matches.
+fn with_flag_ranked<T: RankedItem>(
+    input_word: &str,
+    items_attributes: Vec<(T, Attributes)>,
+) -> Vec<T> {
+    let Some(flag) = resolve_flag(input_word) else {
+        return items_attributes
+            .into_iter()
+            .map(|(item, _attributes)| item)
+            .collect();
+    };
+
+    let flag_idx = items_attributes
+        .iter()
+        .take_while(|(_, attributes)| attributes.is_exact_match)
+        .count();
+
+    // Extract sorted items
+    let mut results: Vec<T> = items_attributes
+        .into_iter()
+        .map(|(item, _attributes)| item)
         .collect();
 
-    debug!(
-        "Found {} matching emojis for single word input",
-        results.len()
-    );
+    // A flag matched by its keywords keeps its rank if it is already higher
+    match results.iter().position(|item| item.is_emoji(&flag)) {
+        Some(idx) if idx <= flag_idx => {}
+        Some(idx) => {
+            let item = results.remove(idx);
+            results.insert(flag_idx, item);
+        }
+        None => results.insert(flag_idx, T::from(flag)),
+    }
+
     results
 }
 
 /// Get the best attributes for an emoji based on its keywords matching the input word
-fn get_emoji_best_attributes(
+///
+/// Generic over the emoji type, so custom emojis are ranked by the same rules.
+fn get_emoji_best_attributes<T: PartialEq + Display>(
     input_word: &str,
-    emoji: &Emoji,
+    emoji: &T,
     keywords: &[String],
-    custom_keyword_most_relevant_emoji: &HashMap<String, Emoji>,
-    keyword_most_relevant_emoji: &HashMap<String, Emoji>,
+    custom_keyword_most_relevant_emoji: &HashMap<String, T>,
+    keyword_most_relevant_emoji: &HashMap<String, T>,
     word_to_recently_searched_inputs_idx: Option<&HashMap<String, usize>>,
     word_to_top_1000_words_idx: &HashMap<String, usize>,
 ) -> Option<Attributes> {
@@ -131,8 +307,8 @@ fn get_emoji_best_attributes(
     let mut emoji_best_attributes: Option<Attributes> = None;
 
     // Process each keyword to find best match
-    for (i, keyword) in keywords.iter().enumerate() {
-        let keyword = pre_process_string(keyword);
+    for (i, original_keyword) in keywords.iter().enumerate() {
+        let keyword = pre_process_string(original_keyword);
 
         let is_emoji_name = i == 0; // First keyword is the emoji name
         let is_single_word = !keyword.contains(' ');
@@ -146,9 +322,9 @@ fn get_emoji_best_attributes(
             }
 
             let is_exact_match = is_exact_match.unwrap();
-            let is_most_relevant_emoji = keyword_most_relevant_emoji.get(&keyword) == Some(&emoji);
+            let is_most_relevant_emoji = keyword_most_relevant_emoji.get(&keyword) == Some(emoji);
             let is_custom_most_relevant_emoji =
-                custom_keyword_most_relevant_emoji.get(&keyword) == Some(&emoji);
+                custom_keyword_most_relevant_emoji.get(&keyword) == Some(emoji);
 
             let prefix_match_recently_searched_inputs_idx = if !is_exact_match {
                 word_to_recently_searched_inputs_idx
@@ -172,6 +348,7 @@ fn get_emoji_best_attributes(
                 is_emoji_name,
                 is_single_word,
                 match_word: keyword.clone(),
+                matched_keyword: original_keyword.clone(),
                 prefix_match_recently_searched_inputs_idx,
                 prefix_match_top_1000_words_idx,
             };
@@ -196,9 +373,9 @@ fn get_emoji_best_attributes(
                 }
 
                 let is_exact_match = is_exact_match.unwrap();
-                let is_most_relevant_emoji = keyword_most_relevant_emoji.get(&word) == Some(&emoji);
+                let is_most_relevant_emoji = keyword_most_relevant_emoji.get(&word) == Some(emoji);
                 let is_custom_most_relevant_emoji =
-                    custom_keyword_most_relevant_emoji.get(&word) == Some(&emoji);
+                    custom_keyword_most_relevant_emoji.get(&word) == Some(emoji);
 
                 let prefix_match_recently_searched_inputs_idx = if !is_exact_match {
                     word_to_recently_searched_inputs_idx
@@ -220,6 +397,7 @@ fn get_emoji_best_attributes(
                     is_emoji_name,
                     is_single_word: false,
                     match_word: word.clone(),
+                    matched_keyword: original_keyword.clone(),
                     prefix_match_recently_searched_inputs_idx,
                     prefix_match_top_1000_words_idx,
                 };
@@ -304,11 +482,7 @@ fn compare_attributes(a: &Attributes, b: &Attributes) -> Ordering {
             &a.prefix_match_recently_searched_inputs_idx,
             &b.prefix_match_recently_searched_inputs_idx,
         ) {
-            (Some(a_idx), Some(b_idx)) => {
-                if a_idx != b_idx {
-                    return a_idx.cmp(b_idx);
-                }
-            }
+            (Some(a_idx), Some(b_idx)) if a_idx != b_idx => return a_idx.cmp(b_idx),
             (Some(_), None) => return Ordering::Less,
             (None, Some(_)) => return Ordering::Greater,
             _ => {}
@@ -326,11 +500,7 @@ fn compare_attributes(a: &Attributes, b: &Attributes) -> Ordering {
             &a.prefix_match_top_1000_words_idx,
             &b.prefix_match_top_1000_words_idx,
         ) {
-            (Some(a_idx), Some(b_idx)) => {
-                if a_idx != b_idx {
-                    return a_idx.cmp(b_idx);
-                }
-            }
+            (Some(a_idx), Some(b_idx)) if a_idx != b_idx => return a_idx.cmp(b_idx),
             (Some(_), None) => return Ordering::Less,
             (None, Some(_)) => return Ordering::Greater,
             _ => {}
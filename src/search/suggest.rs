@@ -0,0 +1,153 @@
+// src/search/suggest.rs
+use crate::constants::{EmojiData, Options};
+use crate::search::emojify::{suggest_emojis_for_keyword, EmojiSuggestion};
+use crate::search::multiple_words::match_emojis_to_words_raw;
+use crate::utils::nlp::parts_of_speech::chunk_noun_phrases;
+use emojis::emoji::Emoji;
+use tracing::{debug, trace};
+
+/// Confidence of the first emoji of a multiple words search, later ones are scaled down
+const MULTIPLE_WORDS_CONFIDENCE: f32 = 0.5;
+
+/// A concept found in a message, e.g. "beach" in "going to the beach with my dog"
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct MessageConcept {
+    /// The words of the concept, joined with spaces
+    pub phrase: String,
+    /// Suggested emojis for the concept, most confident first
+    pub suggestions: Vec<EmojiSuggestion>,
+}
+
+/// Emoji suggestions for a whole message
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct MessageSuggestions {
+    /// Concepts in the order they appear in the message
+    pub concepts: Vec<MessageConcept>,
+    /// Overall top emojis: the best emoji of every concept first, then the runners-up
+    pub top: Vec<Emoji>,
+}
+
+/// Suggest emojis for a message by splitting it into concepts, e.g.
+/// "going to the beach with my dog" -> 🏖️ for "beach" and 🐕 for "dog"
+///
+/// Each noun-phrase chunk is looked up as a whole first. Chunks with multiple words that have
+/// no direct suggestions are split into their single words, and fall back to a multiple words
+/// search when none of the words has suggestions either.
+pub async fn suggest_emojis_for_message(
+    input: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+    max_limit: usize,
+) -> MessageSuggestions {
+    debug!("Suggesting emojis for message: {}", input);
+
+    let input_words_array: Vec<String> = input.split(' ').map(|s| s.to_string()).collect();
+    let chunks = chunk_noun_phrases(&input_words_array);
+
+    let mut concepts: Vec<MessageConcept> = Vec::new();
+
+    for chunk in chunks {
+        let phrase = chunk.join(" ");
+        let suggestions = suggest_emojis_for_keyword(&phrase, emoji_data, options).await;
+
+        if !suggestions.is_empty() {
+            concepts.push(MessageConcept {
+                phrase,
+                suggestions,
+            });
+            continue;
+        }
+
+        if chunk.len() == 1 {
+            continue;
+        }
+
+        // The phrase as a whole is unknown, treat its words as separate concepts
+        trace!("No suggestions for phrase {}, splitting into words", phrase);
+        let mut word_concepts: Vec<MessageConcept> = Vec::new();
+        for word in chunk {
+            let suggestions = suggest_emojis_for_keyword(&word, emoji_data, options).await;
+            if !suggestions.is_empty() {
+                word_concepts.push(MessageConcept {
+                    phrase: word,
+                    suggestions,
+                });
+            }
+        }
+
+        if !word_concepts.is_empty() {
+            concepts.extend(word_concepts);
+            continue;
+        }
+
+        // None of the words is known either, search the phrase
+        trace!("No suggestions for the words of {}, searching them", phrase);
+        let suggestions = get_multiple_words_suggestions(&phrase, emoji_data, options).await;
+        if !suggestions.is_empty() {
+            concepts.push(MessageConcept {
+                phrase,
+                suggestions,
+            });
+        }
+    }
+
+    for concept in concepts.iter_mut() {
+        concept.suggestions.truncate(max_limit);
+    }
+
+    let top = get_top_emojis(&concepts, max_limit);
+
+    debug!(
+        "Found {} concepts and {} top emojis for message",
+        concepts.len(),
+        top.len()
+    );
+    MessageSuggestions { concepts, top }
+}
+
+/// Get suggestions for a phrase from a multiple words search, most confident first
+async fn get_multiple_words_suggestions(
+    phrase: &str,
+    emoji_data: &EmojiData,
+    options: &Options,
+) -> Vec<EmojiSuggestion> {
+    match_emojis_to_words_raw(phrase, emoji_data, options)
+        .await
+        .into_iter()
+        .enumerate()
+        .map(|(i, emoji)| EmojiSuggestion {
+            emoji,
+            confidence: MULTIPLE_WORDS_CONFIDENCE / (i + 1) as f32,
+        })
+        .collect()
+}
+
+/// Get the overall top emojis, taking the best emoji of every concept before any runner-up
+fn get_top_emojis(concepts: &[MessageConcept], max_limit: usize) -> Vec<Emoji> {
+    let mut top: Vec<Emoji> = Vec::new();
+
+    for concept in concepts {
+        if let Some(best) = concept.suggestions.first() {
+            if !top.contains(&best.emoji) {
+                top.push(best.emoji.clone());
+            }
+        }
+    }
+
+    let mut runners_up: Vec<&EmojiSuggestion> = concepts
+        .iter()
+        .flat_map(|concept| concept.suggestions.iter().skip(1))
+        .collect();
+    runners_up.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    for suggestion in runners_up {
+        if !top.contains(&suggestion.emoji) {
+            top.push(suggestion.emoji.clone());
+        }
+    }
+
+    top.truncate(max_limit);
+    top
+}
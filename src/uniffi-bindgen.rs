@@ -0,0 +1,7 @@
+// src/uniffi-bindgen.rs
+//! Generates the foreign language bindings, e.g.
+//! `cargo run --features bindgen --bin uniffi-bindgen generate --library target/release/libemoji_search.dylib --language swift --out-dir out`
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,247 @@
+// src/user_dictionary.rs
+//! Personal keywords of a user, persisted to a local file and merged into the searchable data
+//!
+//! Unlike `Options`, which callers rebuild for every search, the dictionary is loaded once,
+//! merged into `EmojiData` with `merge_into`, and then every change updates the file and
+//! the searchable data together.
+
+use crate::constants::{normalize_keyword, parse_emoji, EmojiData};
+use crate::error::Result;
+use emojis::emoji::Emoji;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// File format of a user dictionary, chosen by the file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserDictionaryFormat {
+    Json,
+    /// Used for files ending in `.cbor`
+    Cbor,
+}
+
+impl UserDictionaryFormat {
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("cbor") => UserDictionaryFormat::Cbor,
+            _ => UserDictionaryFormat::Json,
+        }
+    }
+}
+
+/// The persisted entries, with emojis as strings so the file stays readable
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct UserDictionaryEntries {
+    /// e.g. {"🐶": ["pupper", "doggo"]}
+    emoji_keywords: HashMap<String, Vec<String>>,
+    /// e.g. {"pupper": "🐶"}
+    keyword_most_relevant_emoji: HashMap<String, String>,
+}
+
+/// A user's personal keywords and most relevant emojis, saved to a JSON or CBOR file
+#[derive(Debug, Clone)]
+pub struct UserDictionary {
+    path: PathBuf,
+    format: UserDictionaryFormat,
+    entries: UserDictionaryEntries,
+    /// Normalized keywords the dictionary added to the searchable data, so removing a
+    /// personal keyword never removes the same built-in keyword
+    added_keywords: HashMap<Emoji, HashSet<String>>,
+}
+
+impl UserDictionary {
+    /// Open the dictionary stored at a path, starting empty if the file doesn't exist yet
+    ///
+    /// The format is CBOR for files ending in `.cbor`, JSON otherwise.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let format = UserDictionaryFormat::from_path(&path);
+
+        let entries = match File::open(&path) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                match format {
+                    UserDictionaryFormat::Json => serde_json::from_reader(reader)?,
+                    UserDictionaryFormat::Cbor => serde_cbor::from_reader(reader)?,
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                info!("No user dictionary at {}, starting empty", path.display());
+                UserDictionaryEntries::default()
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        debug!(
+            "Opened user dictionary {}: {} emojis",
+            path.display(),
+            entries.emoji_keywords.len()
+        );
+
+        Ok(Self {
+            path,
+            format,
+            entries,
+            added_keywords: HashMap::new(),
+        })
+    }
+
+    /// Write the dictionary to its file
+    ///
+    /// The file is replaced atomically, so a crash never leaves a half written dictionary.
+    pub fn save(&self) -> Result<()> {
+        // Appended to the whole file name, so "words.json" and "words.cbor" don't share one
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            match self.format {
+                UserDictionaryFormat::Json => {
+                    serde_json::to_writer_pretty(&mut writer, &self.entries)?
+                }
+                UserDictionaryFormat::Cbor => serde_cbor::to_writer(&mut writer, &self.entries)?,
+            }
+            writer.flush()?;
+        }
+
+        fs::rename(&tmp_path, &self.path)?;
+        debug!("Saved user dictionary {}", self.path.display());
+        Ok(())
+    }
+
+    /// Merge all entries into the searchable data, e.g. right after opening the dictionary
+    ///
+    /// Emojis are parsed like the data files, as an emoji or a shortcode, e.g. "🐶" or "dog".
+    pub fn merge_into(&mut self, emoji_data: &mut EmojiData) {
+        for (emoji_str, keywords) in &self.entries.emoji_keywords {
+            match parse_emoji(emoji_str) {
+                Ok(emoji) => add_to_data(&mut self.added_keywords, &emoji, keywords, emoji_data),
+                Err(_) => warn!("Skipping unknown emoji in user dictionary: {}", emoji_str),
+            }
+        }
+
+        for (keyword, emoji_str) in &self.entries.keyword_most_relevant_emoji {
+            match parse_emoji(emoji_str) {
+                Ok(emoji) => emoji_data.set_most_relevant(keyword, &emoji),
+                Err(_) => warn!("Skipping unknown emoji in user dictionary: {}", emoji_str),
+            }
+        }
+    }
+
+    /// Add personal keywords to an emoji, in the dictionary file and in the searchable data
+    pub fn add_keywords(
+        &mut self,
+        emoji: &Emoji,
+        keywords: &[String],
+        emoji_data: &mut EmojiData,
+    ) -> Result<()> {
+        let user_keywords = self
+            .entries
+            .emoji_keywords
+            .entry(emoji.to_string())
+            .or_default();
+        for keyword in keywords {
+            if !user_keywords.contains(keyword) {
+                user_keywords.push(keyword.clone());
+            }
+        }
+
+        add_to_data(&mut self.added_keywords, emoji, keywords, emoji_data);
+        self.save()
+    }
+
+    /// Remove a personal keyword from an emoji, in the dictionary file and in the searchable
+    /// data, where a built-in keyword the emoji already had is kept
+    ///
+    /// # Returns
+    /// Whether the dictionary had the keyword
+    pub fn remove_keyword(
+        &mut self,
+        emoji: &Emoji,
+        keyword: &str,
+        emoji_data: &mut EmojiData,
+    ) -> Result<bool> {
+        let emoji_str = emoji.to_string();
+        let Some(user_keywords) = self.entries.emoji_keywords.get_mut(&emoji_str) else {
+            return Ok(false);
+        };
+
+        let keyword = normalize_keyword(keyword);
+        let num_keywords = user_keywords.len();
+        user_keywords.retain(|k| normalize_keyword(k) != keyword);
+        if user_keywords.len() == num_keywords {
+            return Ok(false);
+        }
+        if user_keywords.is_empty() {
+            self.entries.emoji_keywords.remove(&emoji_str);
+        }
+
+        let was_added = self
+            .added_keywords
+            .get_mut(emoji)
+            .is_some_and(|added| added.remove(&keyword));
+        if was_added {
+            emoji_data.remove_keyword(emoji, &keyword);
+        } else {
+            debug!("Keeping built-in keyword {} of {}", keyword, emoji);
+        }
+
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Make an emoji the most relevant one for a keyword, in the dictionary file and in the
+    /// searchable data
+    pub fn set_most_relevant(
+        &mut self,
+        keyword: &str,
+        emoji: &Emoji,
+        emoji_data: &mut EmojiData,
+    ) -> Result<()> {
+        self.entries
+            .keyword_most_relevant_emoji
+            .insert(keyword.to_string(), emoji.to_string());
+
+        emoji_data.set_most_relevant(keyword, emoji);
+        self.save()
+    }
+
+    /// Get the personal keywords of an emoji
+    pub fn keywords(&self, emoji: &Emoji) -> &[String] {
+        self.entries
+            .emoji_keywords
+            .get(&emoji.to_string())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The file the dictionary is saved to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Add keywords to the searchable data, recording the ones the emoji didn't have yet
+fn add_to_data(
+    added_keywords: &mut HashMap<Emoji, HashSet<String>>,
+    emoji: &Emoji,
+    keywords: &[String],
+    emoji_data: &mut EmojiData,
+) {
+    let existing_keywords = emoji_data.emoji_keywords.get(emoji);
+    let added = added_keywords.entry(emoji.clone()).or_default();
+
+    for keyword in keywords.iter().map(|keyword| normalize_keyword(keyword)) {
+        let is_built_in = existing_keywords.is_some_and(|existing| existing.contains(&keyword));
+        if !keyword.is_empty() && !is_built_in {
+            added.insert(keyword);
+        }
+    }
+
+    emoji_data.add_keywords(emoji, keywords);
+}
@@ -1,5 +1,57 @@
 use emojis::emoji::Emoji;
 use emojis::emoji::Group;
+
+/// All emoji groups, in Unicode order
+pub const GROUPS: [Group; 9] = [
+    Group::SmileysAndEmotion,
+    Group::PeopleAndBody,
+    Group::AnimalsAndNature,
+    Group::FoodAndDrink,
+    Group::TravelAndPlaces,
+    Group::Activities,
+    Group::Objects,
+    Group::Symbols,
+    Group::Flags,
+];
+
 pub fn get_emoji_section() -> Vec<&'static Emoji> {
     Group::SmileysAndEmotion.emojis().collect()
 }
+
+/// Get the display name of a group, e.g. "Smileys & Emotion"
+pub fn group_name(group: Group) -> &'static str {
+    match group {
+        Group::SmileysAndEmotion => "Smileys & Emotion",
+        Group::PeopleAndBody => "People & Body",
+        Group::AnimalsAndNature => "Animals & Nature",
+        Group::FoodAndDrink => "Food & Drink",
+        Group::TravelAndPlaces => "Travel & Places",
+        Group::Activities => "Activities",
+        Group::Objects => "Objects",
+        Group::Symbols => "Symbols",
+        Group::Flags => "Flags",
+    }
+}
+
+/// Get the identifier of a group, e.g. "smileys-and-emotion"
+pub fn group_slug(group: Group) -> &'static str {
+    match group {
+        Group::SmileysAndEmotion => "smileys-and-emotion",
+        Group::PeopleAndBody => "people-and-body",
+        Group::AnimalsAndNature => "animals-and-nature",
+        Group::FoodAndDrink => "food-and-drink",
+        Group::TravelAndPlaces => "travel-and-places",
+        Group::Activities => "activities",
+        Group::Objects => "objects",
+        Group::Symbols => "symbols",
+        Group::Flags => "flags",
+    }
+}
+
+/// Parse a group from its identifier or display name, ignoring case
+pub fn parse_group(s: &str) -> Option<Group> {
+    let s = s.trim();
+    GROUPS.into_iter().find(|&group| {
+        group_slug(group).eq_ignore_ascii_case(s) || group_name(group).eq_ignore_ascii_case(s)
+    })
+}
@@ -2,3 +2,4 @@
 pub mod get_emojis;
 pub mod nlp;
 pub mod preprocess;
+pub mod task;
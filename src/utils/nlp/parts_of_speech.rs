@@ -137,6 +137,41 @@ static OTHERS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .collect()
 });
 
+/// Common verbs that rarely carry the concept of a message, e.g. "going" in "going to the beach"
+static LIGHT_VERBS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        "go", "goes", "going", "went", "gone", "get", "gets", "getting", "got", "want", "wants",
+        "wanted", "need", "needs", "needed", "let", "lets", "take", "takes", "taking", "took",
+        "make", "makes", "making", "made", "try", "trying", "tried", "gonna", "wanna", "am",
+        "been", "did", "does", "doing", "should", "could", "may", "might", "just", "really",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Check whether the word at `idx` is a pronoun, preposition, conjunction, article or some other
+/// function word that carries little meaning on its own.
+///
+/// The previous word is taken into account, e.g. "all" in "calling all" is kept.
+pub fn is_function_word(words: &[String], idx: usize) -> bool {
+    let word = words[idx].as_str();
+    let previous_word = if idx > 0 {
+        Some(words[idx - 1].as_str())
+    } else {
+        None
+    };
+
+    PRONOUNS.contains(word)
+        || PREPOSITIONS.contains(word)
+        || CONJUNCTIONS.contains(word)
+        || ARTICLES.contains(word)
+        || (PREDETERMINERS.contains(word)
+            && !previous_word.is_some_and(|previous| {
+                PREDETERMINERS_EXCEPTIONS_PREVIOUS_WORDS.contains(previous)
+            }))
+        || OTHERS.contains(word)
+}
+
 /// Filter out words that are pronouns, prepositions, conjunctions, articles or some others.
 ///
 /// This helps focus search on meaningful content words rather than function words.
@@ -146,27 +181,40 @@ pub fn filter_parts_of_speech(words: &[String]) -> Vec<String> {
     let filtered = words
         .iter()
         .enumerate()
-        .filter(|(idx, word)| {
-            let previous_word = if *idx > 0 {
-                Some(&words[idx - 1])
-            } else {
-                None
-            };
-
-            // Skip if it's a pronoun, preposition, etc.
-            !(PRONOUNS.contains(word.as_str())
-                || PREPOSITIONS.contains(word.as_str())
-                || CONJUNCTIONS.contains(word.as_str())
-                || ARTICLES.contains(word.as_str())
-                || (PREDETERMINERS.contains(word.as_str())
-                    && !(previous_word.is_some()
-                        && PREDETERMINERS_EXCEPTIONS_PREVIOUS_WORDS
-                            .contains(previous_word.unwrap().as_str())))
-                || OTHERS.contains(word.as_str()))
-        })
+        // Skip if it's a pronoun, preposition, etc.
+        .filter(|(idx, _)| !is_function_word(words, *idx))
         .map(|(_, word)| word.clone())
         .collect();
 
     trace!("Filtered result: {:?}", filtered);
     filtered
 }
+
+/// Split words into noun-phrase-like chunks of adjacent content words.
+///
+/// Function words and light verbs end a chunk, so "going to the beach with my dog" gives
+/// `[["beach"], ["dog"]]` and "red heart for you" gives `[["red", "heart"]]`.
+pub fn chunk_noun_phrases(words: &[String]) -> Vec<Vec<String>> {
+    trace!("Chunking noun phrases from: {:?}", words);
+
+    let mut chunks: Vec<Vec<String>> = Vec::new();
+    let mut current_chunk: Vec<String> = Vec::new();
+
+    for (idx, word) in words.iter().enumerate() {
+        if word.is_empty() || is_function_word(words, idx) || LIGHT_VERBS.contains(word.as_str()) {
+            if !current_chunk.is_empty() {
+                chunks.push(std::mem::take(&mut current_chunk));
+            }
+            continue;
+        }
+
+        current_chunk.push(word.clone());
+    }
+
+    if !current_chunk.is_empty() {
+        chunks.push(current_chunk);
+    }
+
+    trace!("Chunked result: {:?}", chunks);
+    chunks
+}
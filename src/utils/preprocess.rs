@@ -6,7 +6,6 @@ use tracing::trace;
 /// It performs the following operations:
 /// - Remove """:;(),.!? characters
 /// - Replace - with space
-/// - Replace ' with '
 /// - Convert to lowercase
 pub fn pre_process_string(s: &str) -> String {
     trace!("Pre-processing string: {}", s);
@@ -15,8 +14,33 @@ pub fn pre_process_string(s: &str) -> String {
     // Replace special characters
     result = result.replace(&['"', '"', ':', ';', '(', ')', ',', '.', '!', '?'][..], "");
     result = result.replace('-', " ");
-    result = result.replace('\'', "'");
 
     trace!("Pre-processed result: {}", result);
     result
 }
+
+/// Fold common Latin diacritics to their base letter, e.g. "côte d'ivoire" -> "cote d'ivoire"
+///
+/// This lets inputs typed without diacritics match names containing them.
+pub fn fold_diacritics(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'č' => 'c',
+            'ď' | 'đ' => 'd',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+            'ğ' => 'g',
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => 'i',
+            'ł' => 'l',
+            'ñ' | 'ń' | 'ň' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+            'ř' => 'r',
+            'ś' | 'š' | 'ş' | 'ș' => 's',
+            'ť' | 'ţ' | 'ț' => 't',
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            c => c,
+        })
+        .collect()
+}
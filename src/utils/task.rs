@@ -0,0 +1,52 @@
+// src/utils/task.rs
+//! Running the per-emoji matching in parallel where threads are available, and running
+//! searches from synchronous code
+
+/// Handle of a task started with `spawn`, awaiting it gives the task's output
+#[cfg(not(target_arch = "wasm32"))]
+pub type TaskHandle<T> = tokio::task::JoinHandle<T>;
+
+/// Handle of a task started with `spawn`, awaiting it gives the task's output
+#[cfg(target_arch = "wasm32")]
+pub type TaskHandle<T> = std::future::Ready<Result<T, std::convert::Infallible>>;
+
+/// Run a task on the tokio runtime, in parallel with other tasks
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn<F, T>(task: F) -> TaskHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::spawn(async move { task() })
+}
+
+/// Run a task right away, there are no threads nor tokio on wasm32
+#[cfg(target_arch = "wasm32")]
+pub fn spawn<F, T>(task: F) -> TaskHandle<T>
+where
+    F: FnOnce() -> T,
+{
+    std::future::ready(Ok(task()))
+}
+
+/// Runtime driving async searches for synchronous callers, e.g. the C and Python bindings
+#[cfg(not(target_arch = "wasm32"))]
+static RUNTIME: once_cell::sync::Lazy<tokio::runtime::Runtime> = once_cell::sync::Lazy::new(|| {
+    tokio::runtime::Runtime::new().expect("Failed to start the tokio runtime")
+});
+
+/// Run a future to completion from synchronous code
+///
+/// Must not be called from within an async context on the tokio runtime.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    RUNTIME.block_on(future)
+}
+
+/// Run a future to completion from synchronous code
+///
+/// Searches don't wait on anything on wasm32, so the future completes without a runtime.
+#[cfg(target_arch = "wasm32")]
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    futures::executor::block_on(future)
+}
@@ -0,0 +1,324 @@
+// src/validate.rs
+//! Validation of keyword data files, reporting the problems that loading silently skips
+//!
+//! The files are checked as raw JSON, so entries that loading would drop, like unknown
+//! emojis, still show up in the report.
+
+use crate::constants;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::constants::EmojiDataPaths;
+use crate::error::Result;
+use crate::utils::preprocess::pre_process_string;
+use emojis::common::EMOJIS;
+use emojis::emoji::Emoji;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+/// Data file an issue was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum DataFile {
+    EmojiKeywords,
+    KeywordMostRelevantEmoji,
+    EmojiGlossary,
+}
+
+/// Kind of problem found in a data file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationIssueKind {
+    /// An emoji or shortcode that isn't recognized, its entry is skipped when loading
+    UnknownEmoji,
+    /// A keyword listed more than once for the same emoji
+    DuplicateKeyword,
+    /// Different keywords of an emoji that are the same after pre-processing, e.g. "Dog"
+    /// and "dog"
+    EquivalentKeywords,
+    /// A most relevant emoji that doesn't have the keyword, so it is never ranked first
+    OrphanedMostRelevant,
+    /// An emoji without keywords, which no search can return
+    NoKeywords,
+    /// A glossary entry that doesn't agree with the keywords file
+    GlossaryInconsistency,
+}
+
+/// How serious an issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Data is lost or can't be loaded
+    Error,
+    /// Data is loaded but likely not as intended
+    Warning,
+}
+
+/// A problem found in a data file
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct ValidationIssue {
+    pub kind: ValidationIssueKind,
+    pub severity: Severity,
+    pub file: DataFile,
+    /// The key of the entry, an emoji or a keyword
+    pub key: String,
+    pub message: String,
+}
+
+/// All problems found in the data files, serializable as the machine-readable report
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(uniffi::Record))]
+pub struct ValidationReport {
+    pub num_errors: u32,
+    pub num_warnings: u32,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Whether no errors were found, warnings are allowed
+    pub fn is_valid(&self) -> bool {
+        self.num_errors == 0
+    }
+
+    fn push(
+        &mut self,
+        kind: ValidationIssueKind,
+        severity: Severity,
+        file: DataFile,
+        key: &str,
+        message: String,
+    ) {
+        match severity {
+            Severity::Error => self.num_errors += 1,
+            Severity::Warning => self.num_warnings += 1,
+        }
+
+        self.issues.push(ValidationIssue {
+            kind,
+            severity,
+            file,
+            key: key.to_string(),
+            message,
+        });
+    }
+}
+
+/// Validate the contents of the keywords, most relevant emoji and glossary files
+///
+/// # Arguments
+/// * `emoji_keywords` - JSON map from emoji to its keywords
+/// * `keyword_most_relevant_emoji` - JSON map from keyword to emoji
+/// * `emoji_glossary` - JSON map from keyword to emojis
+///
+/// # Returns
+/// The report, or an error if a file isn't valid JSON of the expected shape
+pub fn validate_data(
+    emoji_keywords: &str,
+    keyword_most_relevant_emoji: &str,
+    emoji_glossary: &str,
+) -> Result<ValidationReport> {
+    let emoji_keywords: HashMap<String, Vec<String>> = serde_json::from_str(emoji_keywords)?;
+    let keyword_most_relevant_emoji: HashMap<String, String> =
+        serde_json::from_str(keyword_most_relevant_emoji)?;
+    let emoji_glossary: HashMap<String, Vec<String>> = serde_json::from_str(emoji_glossary)?;
+
+    let mut report = ValidationReport::default();
+
+    // Pre-processed keywords and their words of every recognized emoji, as search matches them
+    let mut emoji_matched_words: HashMap<Emoji, HashSet<String>> = HashMap::new();
+
+    for (emoji_str, keywords) in sorted(&emoji_keywords) {
+        let Some(emoji) = constants::parse_emoji(emoji_str).ok() else {
+            report.push(
+                ValidationIssueKind::UnknownEmoji,
+                Severity::Error,
+                DataFile::EmojiKeywords,
+                emoji_str,
+                format!("{} is not a recognized emoji or shortcode", emoji_str),
+            );
+            continue;
+        };
+
+        if keywords.is_empty() {
+            report.push(
+                ValidationIssueKind::NoKeywords,
+                Severity::Error,
+                DataFile::EmojiKeywords,
+                emoji_str,
+                format!("{} has an empty keyword list", emoji_str),
+            );
+        }
+
+        let mut seen_keywords: HashSet<&str> = HashSet::new();
+        let mut processed_keywords: HashMap<String, &str> = HashMap::new();
+        let matched_words = emoji_matched_words.entry(emoji).or_default();
+
+        for keyword in keywords {
+            if !seen_keywords.insert(keyword) {
+                report.push(
+                    ValidationIssueKind::DuplicateKeyword,
+                    Severity::Warning,
+                    DataFile::EmojiKeywords,
+                    emoji_str,
+                    format!(
+                        "{} lists the keyword \"{}\" more than once",
+                        emoji_str, keyword
+                    ),
+                );
+                continue;
+            }
+
+            let processed_keyword = pre_process_string(keyword);
+            if let Some(other) = processed_keywords.get(&processed_keyword) {
+                report.push(
+                    ValidationIssueKind::EquivalentKeywords,
+                    Severity::Warning,
+                    DataFile::EmojiKeywords,
+                    emoji_str,
+                    format!(
+                        "{} has the keywords \"{}\" and \"{}\", which both search as \"{}\"",
+                        emoji_str, other, keyword, processed_keyword
+                    ),
+                );
+            } else {
+                matched_words.extend(processed_keyword.split(' ').map(|w| w.to_string()));
+                matched_words.insert(processed_keyword.clone());
+                processed_keywords.insert(processed_keyword, keyword);
+            }
+        }
+    }
+
+    // Emojis that exist but have no entry at all
+    let mut emojis_without_keywords: Vec<&Emoji> = EMOJIS
+        .iter()
+        .filter(|emoji| !emoji_matched_words.contains_key(*emoji))
+        .collect();
+    emojis_without_keywords.sort_by_key(|emoji| emoji.to_string());
+    for emoji in emojis_without_keywords {
+        report.push(
+            ValidationIssueKind::NoKeywords,
+            Severity::Warning,
+            DataFile::EmojiKeywords,
+            &emoji.to_string(),
+            format!("{} ({}) has no keywords", emoji, emoji.name()),
+        );
+    }
+
+    for (keyword, emoji_str) in sorted(&keyword_most_relevant_emoji) {
+        let Some(emoji) = constants::parse_emoji(emoji_str).ok() else {
+            report.push(
+                ValidationIssueKind::UnknownEmoji,
+                Severity::Error,
+                DataFile::KeywordMostRelevantEmoji,
+                keyword,
+                format!("{} is not a recognized emoji or shortcode", emoji_str),
+            );
+            continue;
+        };
+
+        let has_keyword = emoji_matched_words
+            .get(&emoji)
+            .is_some_and(|words| words.contains(&pre_process_string(keyword)));
+        if !has_keyword {
+            report.push(
+                ValidationIssueKind::OrphanedMostRelevant,
+                Severity::Warning,
+                DataFile::KeywordMostRelevantEmoji,
+                keyword,
+                format!(
+                    "{} is the most relevant emoji for \"{}\" but doesn't have that keyword",
+                    emoji_str, keyword
+                ),
+            );
+        }
+    }
+
+    for (keyword, emoji_strs) in sorted(&emoji_glossary) {
+        // The glossary is looked up with pre-processed words
+        let processed_keyword = pre_process_string(keyword);
+        if processed_keyword != *keyword {
+            report.push(
+                ValidationIssueKind::GlossaryInconsistency,
+                Severity::Warning,
+                DataFile::EmojiGlossary,
+                keyword,
+                format!(
+                    "\"{}\" is never looked up, searches use \"{}\"",
+                    keyword, processed_keyword
+                ),
+            );
+        }
+
+        let mut seen_emojis: HashSet<&str> = HashSet::new();
+        for emoji_str in emoji_strs {
+            if !seen_emojis.insert(emoji_str) {
+                report.push(
+                    ValidationIssueKind::GlossaryInconsistency,
+                    Severity::Warning,
+                    DataFile::EmojiGlossary,
+                    keyword,
+                    format!("\"{}\" lists {} more than once", keyword, emoji_str),
+                );
+                continue;
+            }
+
+            match constants::parse_emoji(emoji_str).ok() {
+                None => report.push(
+                    ValidationIssueKind::UnknownEmoji,
+                    Severity::Error,
+                    DataFile::EmojiGlossary,
+                    keyword,
+                    format!("{} is not a recognized emoji or shortcode", emoji_str),
+                ),
+                Some(emoji) if !emoji_matched_words.contains_key(&emoji) => report.push(
+                    ValidationIssueKind::GlossaryInconsistency,
+                    Severity::Warning,
+                    DataFile::EmojiGlossary,
+                    keyword,
+                    format!(
+                        "\"{}\" lists {}, which has no entry in the keywords file",
+                        keyword, emoji_str
+                    ),
+                ),
+                Some(_) => {}
+            }
+        }
+    }
+
+    info!(
+        "Validated emoji data: {} errors, {} warnings",
+        report.num_errors, report.num_warnings
+    );
+    Ok(report)
+}
+
+/// Validate the data files, using the embedded file for every path that isn't given
+#[cfg(not(target_arch = "wasm32"))]
+pub fn validate_data_files(paths: &EmojiDataPaths) -> Result<ValidationReport> {
+    info!("Validating emoji data files {:?}", paths);
+
+    validate_data(
+        &constants::read_data_file(
+            paths.emoji_keywords.as_deref(),
+            constants::EMBEDDED_EMOJI_KEYWORDS,
+        )?,
+        &constants::read_data_file(
+            paths.keyword_most_relevant_emoji.as_deref(),
+            constants::EMBEDDED_KEYWORD_MOST_RELEVANT_EMOJI,
+        )?,
+        &constants::read_data_file(
+            paths.emoji_glossary.as_deref(),
+            constants::EMBEDDED_EMOJI_GLOSSARY,
+        )?,
+    )
+}
+
+/// Entries sorted by key, so reports are stable across runs
+fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<(&String, &V)> = map.iter().collect();
+    entries.sort_by_key(|(key, _)| *key);
+    entries
+}
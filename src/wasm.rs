@@ -0,0 +1,182 @@
+// src/wasm.rs
+//! JavaScript bindings for the wasm32 build, e.g.
+//! `wasm-pack build --target web -- --no-default-features`
+//!
+//! ```js
+//! const emojiData = loadEmojiData();
+//! searchEmojis("dog", 10, { recentlySearchedInputs: ["doggo"] }, emojiData); // ["🐶", ...]
+//!
+//! const withParrot = withCustomEmojis([{ id: "partyparrot", name: "party parrot", image: "parrot.gif" }], emojiData);
+//! searchItems("parrot", 10, undefined, withParrot);
+//! // [{ type: "custom", id: "partyparrot", ... }, { type: "unicode", emoji: "🦜" }]
+//! ```
+
+use crate::constants::{self, Options};
+use crate::custom_emoji::{CustomEmoji, SearchResult};
+use crate::error::FfiError;
+use crate::utils::task::block_on;
+use emojis::emoji::Emoji;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Emoji data loaded once and passed to every search
+#[wasm_bindgen(js_name = EmojiData)]
+pub struct WasmEmojiData(constants::EmojiData);
+
+/// Search options as passed from JavaScript, every field is optional, e.g.
+/// `{ customEmojiKeywords: { "🐶": ["pupper"] }, customKeywordMostRelevantEmoji: { "pupper": "🐶" } }`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsOptions {
+    custom_emoji_keywords: Option<HashMap<String, Vec<String>>>,
+    custom_keyword_most_relevant_emoji: Option<HashMap<String, String>>,
+    recently_searched_inputs: Option<Vec<String>>,
+}
+
+/// A Unicode or custom emoji search result as returned to JavaScript, e.g.
+/// `{ type: "unicode", emoji: "🦜" }` or `{ type: "custom", id: "partyparrot", ... }`
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum JsSearchResult<'a> {
+    Unicode {
+        emoji: String,
+    },
+    Custom {
+        #[serde(flatten)]
+        emoji: &'a CustomEmoji,
+    },
+}
+
+/// Load the emoji data bundled with the module
+#[wasm_bindgen(js_name = loadEmojiData)]
+pub fn load_emoji_data() -> Result<WasmEmojiData, JsError> {
+    let emoji_data = constants::load_emoji_data().map_err(FfiError::from)?;
+    Ok(WasmEmojiData(emoji_data))
+}
+
+/// Search emojis, see `search_emojis`
+#[wasm_bindgen(js_name = searchEmojis)]
+pub fn search_emojis(
+    input: &str,
+    max_limit: Option<u32>,
+    options: JsValue,
+    emoji_data: &WasmEmojiData,
+) -> Result<Vec<String>, JsError> {
+    let options = parse_options(options)?;
+    let emojis = block_on(crate::search_emojis(
+        input,
+        max_limit,
+        options,
+        &emoji_data.0,
+    ))?;
+
+    Ok(to_strings(&emojis))
+}
+
+/// Search best matching emojis, see `search_best_matching_emojis`
+#[wasm_bindgen(js_name = searchBestMatchingEmojis)]
+pub fn search_best_matching_emojis(
+    input: &str,
+    max_limit: Option<u32>,
+    options: JsValue,
+    emoji_data: &WasmEmojiData,
+) -> Result<Vec<String>, JsError> {
+    let options = parse_options(options)?;
+    let emojis = block_on(crate::search_best_matching_emojis(
+        input,
+        max_limit,
+        options,
+        &emoji_data.0,
+    ))?;
+
+    Ok(to_strings(&emojis))
+}
+
+/// Add custom emojis to a copy of the emoji data, see `with_custom_emojis`
+///
+/// The custom emojis are objects with an `id`, a `name`, an `image` and optional `keywords`.
+#[wasm_bindgen(js_name = withCustomEmojis)]
+pub fn with_custom_emojis(
+    custom_emojis: JsValue,
+    emoji_data: &WasmEmojiData,
+) -> Result<WasmEmojiData, JsError> {
+    let custom_emojis: Vec<CustomEmoji> = serde_wasm_bindgen::from_value(custom_emojis)?;
+    let mut emoji_data = emoji_data.0.clone();
+    emoji_data.add_custom_emojis(custom_emojis);
+
+    Ok(WasmEmojiData(emoji_data))
+}
+
+/// Search emojis and custom emojis, see `search_items`
+#[wasm_bindgen(js_name = searchItems)]
+pub fn search_items(
+    input: &str,
+    max_limit: Option<u32>,
+    options: JsValue,
+    emoji_data: &WasmEmojiData,
+) -> Result<JsValue, JsError> {
+    let options = parse_options(options)?;
+    let items = block_on(crate::search_items(
+        input,
+        max_limit,
+        options,
+        &emoji_data.0,
+    ))?;
+
+    to_js_items(&items)
+}
+
+/// Search best matching emojis and custom emojis, see `search_best_matching_items`
+#[wasm_bindgen(js_name = searchBestMatchingItems)]
+pub fn search_best_matching_items(
+    input: &str,
+    max_limit: Option<u32>,
+    options: JsValue,
+    emoji_data: &WasmEmojiData,
+) -> Result<JsValue, JsError> {
+    let options = parse_options(options)?;
+    let items = block_on(crate::search_best_matching_items(
+        input,
+        max_limit,
+        options,
+        &emoji_data.0,
+    ))?;
+
+    to_js_items(&items)
+}
+
+/// Convert options from a JavaScript object, `undefined` and `null` mean no options
+fn parse_options(options: JsValue) -> Result<Option<Options>, JsError> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(None);
+    }
+
+    let options: JsOptions = serde_wasm_bindgen::from_value(options)?;
+    let options = Options::from_emoji_strings(
+        options.custom_emoji_keywords,
+        options.custom_keyword_most_relevant_emoji,
+        options.recently_searched_inputs,
+    )
+    .map_err(FfiError::from)?;
+
+    Ok(Some(options))
+}
+
+fn to_strings(emojis: &[Emoji]) -> Vec<String> {
+    emojis.iter().map(|emoji| emoji.to_string()).collect()
+}
+
+fn to_js_items(items: &[SearchResult]) -> Result<JsValue, JsError> {
+    let items: Vec<JsSearchResult> = items
+        .iter()
+        .map(|item| match item {
+            SearchResult::Unicode { emoji } => JsSearchResult::Unicode {
+                emoji: emoji.to_string(),
+            },
+            SearchResult::Custom { emoji } => JsSearchResult::Custom { emoji },
+        })
+        .collect();
+
+    Ok(serde_wasm_bindgen::to_value(&items)?)
+}
@@ -0,0 +1,203 @@
+// src/watch.rs
+//! Hot reload of keyword data files into the global emoji data, enabled by the `watch` feature
+//!
+//! A changed file is parsed like when loading, unknown emojis are skipped with a warning,
+//! and then replaces its `Arc` field of the global emoji data. A file that can't be read,
+//! isn't valid JSON or has no valid entry at all leaves the previous data in place and is
+//! reported through the error callback.
+
+use crate::constants::{self, EmojiData, EmojiDataPaths};
+use crate::error::{EmojiSearchError, FfiError, FfiResult, Result};
+use crate::global::{set_global_emoji_data, update_global_emoji_data};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, error, info};
+
+/// Time to wait for more changes before reloading, editors often write a file in several steps
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+/// A data file that can be reloaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum DataFile {
+    EmojiKeywords,
+    KeywordMostRelevantEmoji,
+    EmojiGlossary,
+}
+
+/// Watches data files and reloads them into the global emoji data, stops watching when dropped
+pub struct EmojiDataWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+/// Load the data files into the global emoji data and reload them whenever they change
+///
+/// The keyword, most relevant emoji and glossary files are watched, files without a path
+/// use the embedded data and aren't watched.
+///
+/// # Arguments
+/// * `paths` - The data files to load and watch
+/// * `on_error` - Called with the path and the error when a changed file can't be reloaded
+///
+/// # Returns
+/// The watcher, or an error if the files can't be loaded initially or watched
+pub fn watch_emoji_data<F>(paths: EmojiDataPaths, on_error: F) -> FfiResult<EmojiDataWatcher>
+where
+    F: Fn(&Path, FfiError) + Send + 'static,
+{
+    let emoji_data = constants::load_emoji_data_from_paths(&paths)?;
+    set_global_emoji_data(Arc::new(emoji_data));
+
+    let mut watched_files: HashMap<PathBuf, DataFile> = HashMap::new();
+    for (path, data_file) in [
+        (paths.emoji_keywords, DataFile::EmojiKeywords),
+        (
+            paths.keyword_most_relevant_emoji,
+            DataFile::KeywordMostRelevantEmoji,
+        ),
+        (paths.emoji_glossary, DataFile::EmojiGlossary),
+    ] {
+        if let Some(path) = path.as_deref().and_then(normalize_path) {
+            watched_files.insert(path, data_file);
+        }
+    }
+
+    let (tx, rx) = channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(tx).map_err(watch_error)?;
+
+    // Watch the directories, editors often save by replacing the file
+    let dirs: HashSet<&Path> = watched_files.keys().filter_map(|p| p.parent()).collect();
+    for dir in dirs {
+        debug!("Watching {}", dir.display());
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+    }
+
+    thread::spawn(move || {
+        // Stops when the watcher is dropped, which closes the channel
+        while let Ok(event) = rx.recv() {
+            let mut changed_files = HashSet::new();
+            collect_changed_files(event, &watched_files, &mut changed_files);
+
+            loop {
+                match rx.recv_timeout(DEBOUNCE_DURATION) {
+                    Ok(event) => collect_changed_files(event, &watched_files, &mut changed_files),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            for (path, data_file) in changed_files {
+                match reload_file(&path, data_file) {
+                    Ok(()) => info!("Reloaded {}", path.display()),
+                    Err(e) => {
+                        error!("Failed to reload {}: {}", path.display(), e);
+                        on_error(&path, FfiError::from(e));
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(EmojiDataWatcher { _watcher: watcher })
+}
+
+/// Add the watched files touched by an event
+fn collect_changed_files(
+    event: notify::Result<Event>,
+    watched_files: &HashMap<PathBuf, DataFile>,
+    changed_files: &mut HashSet<(PathBuf, DataFile)>,
+) {
+    let event = match event {
+        Ok(event) => event,
+        Err(e) => {
+            error!("Error watching data files: {}", e);
+            return;
+        }
+    };
+
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+        return;
+    }
+
+    for path in event.paths.iter().filter_map(|path| normalize_path(path)) {
+        if let Some(&data_file) = watched_files.get(&path) {
+            changed_files.insert((path, data_file));
+        }
+    }
+}
+
+/// Parse a data file, then swap it into the global emoji data
+fn reload_file(path: &Path, data_file: DataFile) -> Result<()> {
+    let json = fs::read_to_string(path)?;
+
+    let update: Box<dyn FnOnce(&EmojiData) -> EmojiData> = match data_file {
+        DataFile::EmojiKeywords => {
+            let emoji_keywords = constants::parse_emoji_keywords(serde_json::from_str(&json)?);
+            check_has_entries(path, emoji_keywords.len())?;
+            let phrase_emojis = Arc::new(constants::build_phrase_emojis(&emoji_keywords));
+            let emoji_keywords = Arc::new(emoji_keywords);
+            Box::new(move |emoji_data| EmojiData {
+                emoji_keywords,
+                phrase_emojis,
+                ..emoji_data.clone()
+            })
+        }
+        DataFile::KeywordMostRelevantEmoji => {
+            let keyword_most_relevant_emoji =
+                constants::parse_keyword_most_relevant_emoji(serde_json::from_str(&json)?);
+            check_has_entries(path, keyword_most_relevant_emoji.len())?;
+            let keyword_most_relevant_emoji = Arc::new(keyword_most_relevant_emoji);
+            Box::new(move |emoji_data| EmojiData {
+                keyword_most_relevant_emoji,
+                ..emoji_data.clone()
+            })
+        }
+        DataFile::EmojiGlossary => {
+            let emoji_glossary = constants::parse_emoji_glossary(serde_json::from_str(&json)?);
+            check_has_entries(path, emoji_glossary.len())?;
+            let emoji_glossary = Arc::new(emoji_glossary);
+            Box::new(move |emoji_data| EmojiData {
+                emoji_glossary,
+                ..emoji_data.clone()
+            })
+        }
+    };
+
+    update_global_emoji_data(update)
+        .map_err(|e| EmojiSearchError::Search(format!("Failed to update emoji data: {}", e)))
+}
+
+/// Reject a parsed file without entries, e.g. `{}` or a file whose emojis are all unknown,
+/// which would wipe the previous data
+fn check_has_entries(path: &Path, num_entries: usize) -> Result<()> {
+    if num_entries == 0 {
+        return Err(EmojiSearchError::InvalidInput(format!(
+            "{} has no valid entries",
+            path.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Make a path comparable with the paths of events, which are absolute
+fn normalize_path(path: &Path) -> Option<PathBuf> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    Some(fs::canonicalize(dir).ok()?.join(path.file_name()?))
+}
+
+fn watch_error(e: notify::Error) -> FfiError {
+    error!("Failed to watch data files: {}", e);
+    FfiError::Io
+}
@@ -0,0 +1,57 @@
+// tests/c/smoke.c
+// Smoke test of the C API, linked against the staticlib by tests/capi.rs
+
+#include <stdio.h>
+#include <string.h>
+#include "emoji_search.h"
+
+#define CHECK(condition, message)                                   \
+  if (!(condition)) {                                               \
+    const char *error = emoji_search_last_error_message();          \
+    fprintf(stderr, "%s: %s\n", message, error ? error : "no error"); \
+    return 1;                                                       \
+  }
+
+int main(void) {
+  EmojiData *data = NULL;
+  CHECK(emoji_search_data_load(&data) == EMOJI_SEARCH_STATUS_OK, "load failed");
+  CHECK(emoji_search_last_error_message() == NULL, "error message after success");
+
+  struct EmojiSearchStringArray results;
+  CHECK(emoji_search_search(data, "dog", 5, &results) == EMOJI_SEARCH_STATUS_OK,
+        "search failed");
+  CHECK(results.len > 0 && results.len <= 5, "unexpected number of results");
+  for (size_t i = 0; i < results.len; i++) {
+    printf("%s\n", results.items[i]);
+  }
+  emoji_search_string_array_free(results);
+
+  CHECK(emoji_search_search_best_matching(data, "happy birthday party", 0, &results) ==
+            EMOJI_SEARCH_STATUS_OK,
+        "best matching search failed");
+  CHECK(results.len > 0 && results.len <= 24, "unexpected number of best matching results");
+  emoji_search_string_array_free(results);
+
+  const char *keywords[] = {"party", "bird"};
+  CHECK(emoji_search_data_add_custom_emoji(data, "partyparrot", "party parrot", "parrot.gif",
+                                           keywords, 2) == EMOJI_SEARCH_STATUS_OK,
+        "adding a custom emoji failed");
+  CHECK(emoji_search_search_items(data, "partyparrot", 5, &results) == EMOJI_SEARCH_STATUS_OK,
+        "item search failed");
+  CHECK(results.len > 0 && strcmp(results.items[0], ":partyparrot:") == 0,
+        "custom emoji not found");
+  emoji_search_string_array_free(results);
+
+  CHECK(emoji_search_search_best_matching_items(data, "party birds", 0, &results) ==
+            EMOJI_SEARCH_STATUS_OK,
+        "best matching item search failed");
+  CHECK(results.len > 0, "no best matching items");
+  emoji_search_string_array_free(results);
+
+  CHECK(emoji_search_search(data, NULL, 0, &results) == EMOJI_SEARCH_STATUS_INVALID_INPUT,
+        "null input accepted");
+  CHECK(emoji_search_last_error_message() != NULL, "no error message for null input");
+
+  emoji_search_data_free(data);
+  return 0;
+}
@@ -0,0 +1,69 @@
+// tests/capi.rs
+//! Compiles `tests/c/smoke.c` against the generated header and the staticlib and runs it
+//!
+//! Skipped when there is no C compiler, set `CC` to use another one than `cc`.
+
+#![cfg(target_os = "linux")]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// System libraries the staticlib needs, as printed by `--print native-static-libs`
+const NATIVE_STATIC_LIBS: [&str; 6] = ["-lgcc_s", "-lutil", "-lrt", "-lpthread", "-lm", "-ldl"];
+
+/// The directory with the staticlib and the header, the parent of the `deps` directory of
+/// this test
+fn target_dir() -> PathBuf {
+    let test_exe = env::current_exe().expect("failed to get the test executable");
+    test_exe
+        .parent()
+        .and_then(Path::parent)
+        .expect("test executable outside of a target directory")
+        .to_path_buf()
+}
+
+#[test]
+fn c_smoke_test() {
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    if Command::new(&cc).arg("--version").output().is_err() {
+        eprintln!("skipping the C smoke test, {} not found", cc);
+        return;
+    }
+
+    let target_dir = target_dir();
+    let staticlib = target_dir.join("libemoji_search.a");
+    assert!(staticlib.is_file(), "{} not found", staticlib.display());
+
+    let smoke_exe = target_dir.join("capi-smoke");
+    let output = Command::new(&cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(&target_dir)
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/c/smoke.c"))
+        .arg(&staticlib)
+        .args(NATIVE_STATIC_LIBS)
+        .arg("-o")
+        .arg(&smoke_exe)
+        .output()
+        .expect("failed to run the C compiler");
+    assert!(
+        output.status.success(),
+        "failed to compile tests/c/smoke.c:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(&smoke_exe)
+        .output()
+        .expect("failed to run the C smoke test");
+    assert!(
+        output.status.success(),
+        "C smoke test failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.lines().any(|line| line == "🐕"), "{}", stdout);
+}
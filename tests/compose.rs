@@ -0,0 +1,63 @@
+// tests/compose.rs
+//! Composed emoji sequences and where they are ranked among the search results
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::search::compose_emoji_sequence;
+use emoji_search::search_emojis;
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+fn composed(input: &str) -> Option<String> {
+    compose_emoji_sequence(input).map(|emoji| emoji.to_string())
+}
+
+#[test]
+fn skin_tone_needs_skin_or_a_person() {
+    assert_eq!(
+        composed("woman firefighter medium skin").as_deref(),
+        Some("👩🏽‍🚒")
+    );
+    assert_eq!(composed("woman firefighter dark").as_deref(), Some("👩🏿‍🚒"));
+    assert_eq!(composed("thumbs up dark").as_deref(), Some("👍🏿"));
+    assert_eq!(composed("woman dark skin tone").as_deref(), Some("👩🏿"));
+
+    // A trailing tone word without a person or role isn't a skin tone
+    assert_eq!(composed("dark"), None);
+    assert_eq!(composed("medium light"), None);
+    assert_eq!(composed("star light"), None);
+    assert_eq!(composed("night sky dark"), None);
+}
+
+#[test]
+fn several_people_are_not_a_person() {
+    assert_eq!(composed("man woman"), None);
+    assert_eq!(composed("man woman dark skin"), None);
+    assert_eq!(composed("family man woman girl").as_deref(), Some("👨‍👩‍👧"));
+}
+
+#[tokio::test]
+async fn composed_sequence_is_ranked_first() {
+    for (input, composed) in [
+        ("woman dark skin", "👩🏿"),
+        ("woman medium skin", "👩🏽"),
+        ("woman firefighter medium skin", "👩🏽‍🚒"),
+        ("thumbs up dark skin", "👍🏿"),
+    ] {
+        let results = search_emojis(input, None, None, &EMOJI_DATA).await.unwrap();
+        assert_eq!(results[0].as_str(), composed, "{}", input);
+        assert_eq!(
+            results.iter().filter(|e| e.as_str() == composed).count(),
+            1,
+            "{}",
+            input
+        );
+    }
+
+    // An input without a composed sequence keeps the matched ranking
+    let results = search_emojis("man woman", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert_ne!(results[0].as_str(), "👨");
+}
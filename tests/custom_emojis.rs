@@ -0,0 +1,100 @@
+// tests/custom_emojis.rs
+//! Custom emojis searched together with the Unicode emojis
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::custom_emoji::{CustomEmoji, SearchResult};
+use emoji_search::{search_best_matching_items, search_items, with_custom_emojis};
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> = Lazy::new(|| {
+    let emoji_data = load_emoji_data().expect("failed to load the embedded emoji data");
+    let custom_emojis = vec![
+        custom_emoji("partyparrot", "party parrot", &["Dance", "celebrate"]),
+        custom_emoji("shipit", "ship it", &["squirrel"]),
+    ];
+    (*with_custom_emojis(custom_emojis, &emoji_data)).clone()
+});
+
+fn custom_emoji(id: &str, name: &str, keywords: &[&str]) -> CustomEmoji {
+    CustomEmoji {
+        id: id.to_string(),
+        name: name.to_string(),
+        image: format!("{}.gif", id),
+        keywords: keywords.iter().map(|keyword| keyword.to_string()).collect(),
+    }
+}
+
+fn custom_ids(items: &[SearchResult]) -> Vec<&str> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            SearchResult::Custom { emoji } => Some(emoji.id.as_str()),
+            SearchResult::Unicode { .. } => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn custom_emojis_match_name_id_and_keywords() {
+    let items = search_items(":partyparrot:", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert_eq!(items[0].to_string(), ":partyparrot:");
+
+    // Prefixes of the words of the name and normalized keywords
+    let items = search_items("parr", None, None, &EMOJI_DATA).await.unwrap();
+    assert_eq!(custom_ids(&items), ["partyparrot"]);
+    let items = search_items("danc", None, None, &EMOJI_DATA).await.unwrap();
+    assert_eq!(custom_ids(&items), ["partyparrot"]);
+
+    // Unicode emojis are still found
+    let items = search_items("squirrel", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert!(items.iter().any(|item| item.to_string() == "🐿️"));
+    assert_eq!(custom_ids(&items), ["shipit"]);
+
+    let items = search_items("zebra", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert!(custom_ids(&items).is_empty());
+}
+
+#[tokio::test]
+async fn best_matching_items_match_stemmed_words() {
+    let items = search_best_matching_items("celebrating parrots", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert_eq!(items[0].to_string(), ":partyparrot:");
+
+    let items = search_best_matching_items("parrots", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert!(custom_ids(&items).contains(&"partyparrot"));
+}
+
+#[tokio::test]
+async fn replaced_and_removed_custom_emojis_are_reindexed() {
+    let mut emoji_data = EMOJI_DATA.clone();
+    emoji_data.add_custom_emoji(custom_emoji("partyparrot", "disco bird", &[]));
+    assert_eq!(emoji_data.custom_emojis.len(), 2);
+
+    let items = search_items("dance", None, None, &emoji_data)
+        .await
+        .unwrap();
+    assert!(custom_ids(&items).is_empty());
+    let items = search_items("disco", None, None, &emoji_data)
+        .await
+        .unwrap();
+    assert_eq!(custom_ids(&items), ["partyparrot"]);
+
+    assert!(emoji_data.remove_custom_emoji("partyparrot"));
+    assert!(!emoji_data.remove_custom_emoji("partyparrot"));
+    let items = search_items("disco", None, None, &emoji_data)
+        .await
+        .unwrap();
+    assert!(custom_ids(&items).is_empty());
+
+    // Clones taken before are unaffected
+    assert!(EMOJI_DATA.custom_emojis.get("partyparrot").is_some());
+}
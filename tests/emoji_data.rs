@@ -0,0 +1,114 @@
+// tests/emoji_data.rs
+//! Adding and removing keywords and emojis of the searchable data
+
+use emoji_search::constants::{load_emoji_data, EmojiData, Options};
+use emoji_search::search::match_emojis_to_word;
+use emoji_search::search_emojis;
+use emojis::emoji::Emoji;
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+fn emoji(s: &str) -> Emoji {
+    emojis::get(s).unwrap_or_else(|| panic!("{} is not an emoji", s))
+}
+
+fn keywords(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+#[test]
+fn add_keywords_normalizes_and_skips_existing() {
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+    let num_keywords = emoji_data.emoji_keywords[&dog].len();
+
+    emoji_data.add_keywords(&dog, &keywords(&[" Pupper ", "puppy", "", "beach buddy"]));
+
+    let dog_keywords = &emoji_data.emoji_keywords[&dog];
+    assert_eq!(dog_keywords.len(), num_keywords + 2);
+    assert_eq!(&dog_keywords[num_keywords..], ["pupper", "beach buddy"]);
+    assert!(emoji_data.phrase_emojis["beach buddy"].contains(&dog));
+
+    // Clones taken before are unaffected
+    assert_eq!(EMOJI_DATA.emoji_keywords[&dog].len(), num_keywords);
+    assert!(!EMOJI_DATA.phrase_emojis.contains_key("beach buddy"));
+}
+
+#[test]
+fn add_keywords_keeps_the_emoji_name_first() {
+    let dog = emoji("🐶");
+    let mut emoji_data = EmojiData::new();
+
+    emoji_data.add_keywords(&dog, &keywords(&["pupper", "doggo"]));
+
+    assert_eq!(
+        emoji_data.emoji_keywords[&dog],
+        keywords(&["dog face", "pupper", "doggo"])
+    );
+    assert!(emoji_data.emoji_set.contains(&dog));
+    assert!(emoji_data.phrase_emojis["dog face"].contains(&dog));
+
+    // Adding the name itself doesn't duplicate it
+    let mut emoji_data = EmojiData::new();
+    emoji_data.add_keywords(&dog, &keywords(&["Dog Face", "pupper"]));
+    assert_eq!(
+        emoji_data.emoji_keywords[&dog],
+        keywords(&["dog face", "pupper"])
+    );
+}
+
+#[test]
+fn mutations_leave_the_glossary_unchanged() {
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+    let glossary = EMOJI_DATA.emoji_glossary.clone();
+
+    emoji_data.add_keywords(&dog, &keywords(&["pupper"]));
+    assert!(!emoji_data.emoji_glossary.contains_key("pupper"));
+
+    emoji_data.remove_keyword(&dog, "puppy");
+    emoji_data.remove_emoji(&emoji("🐱"));
+    assert_eq!(*emoji_data.emoji_glossary, *glossary);
+}
+
+#[tokio::test]
+async fn remove_keyword() {
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+    let options = Options::default();
+    emoji_data.set_most_relevant("doggo", &dog);
+
+    assert!(match_emojis_to_word("doggo", &emoji_data, &options)
+        .await
+        .contains(&dog));
+
+    assert!(emoji_data.remove_keyword(&dog, "Doggo"));
+    assert!(!emoji_data.remove_keyword(&dog, "doggo"));
+    assert!(!emoji_data.emoji_keywords[&dog].contains(&"doggo".to_string()));
+    assert!(!emoji_data.keyword_most_relevant_emoji.contains_key("doggo"));
+    assert!(!match_emojis_to_word("doggo", &emoji_data, &options)
+        .await
+        .contains(&dog));
+}
+
+#[tokio::test]
+async fn remove_emoji() {
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+
+    assert!(emoji_data.remove_emoji(&dog));
+    assert!(!emoji_data.remove_emoji(&dog));
+    assert!(!emoji_data.emoji_set.contains(&dog));
+    assert!(!emoji_data.emoji_keywords.contains_key(&dog));
+    assert!(emoji_data
+        .keyword_most_relevant_emoji
+        .values()
+        .all(|e| e != &dog));
+    assert!(emoji_data.phrase_emojis.values().all(|e| !e.contains(&dog)));
+
+    let results = search_emojis("dog", None, None, &emoji_data).await.unwrap();
+    assert!(!results.contains(&dog));
+    assert!(EMOJI_DATA.emoji_set.contains(&dog));
+}
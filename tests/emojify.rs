@@ -0,0 +1,78 @@
+// tests/emojify.rs
+//! Emojified words of free texts and how they are rendered
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::search::{EmojifyMode, EmojifyToken};
+use emoji_search::{emojify, emojify_text};
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+async fn emojify_tokens(input: &str) -> Vec<EmojifyToken> {
+    emojify(input, None, None, &EMOJI_DATA).await.unwrap()
+}
+
+async fn render(input: &str, mode: EmojifyMode) -> String {
+    emojify_text(input, mode, None, &EMOJI_DATA).await.unwrap()
+}
+
+fn spans(tokens: &[EmojifyToken]) -> Vec<(u32, u32, &str)> {
+    tokens
+        .iter()
+        .map(|token| (token.start, token.end, token.word.as_str()))
+        .collect()
+}
+
+#[tokio::test]
+async fn words_are_split_without_punctuation_and_function_words() {
+    let tokens = emojify_tokens("Cats, dogs... and (pizza)!").await;
+    assert_eq!(
+        spans(&tokens),
+        [(0, 4, "Cats"), (6, 10, "dogs"), (19, 24, "pizza")]
+    );
+    assert_eq!(tokens[2].suggestions[0].emoji.as_str(), "🍕");
+
+    // Byte offsets, not characters
+    let tokens = emojify_tokens("café ☕ tacos").await;
+    assert_eq!(spans(&tokens).last(), Some(&(10, 15, "tacos")));
+
+    assert!(emojify_tokens("   ").await.is_empty());
+    assert!(emojify_tokens("zzyzx").await.is_empty());
+}
+
+#[tokio::test]
+async fn unknown_words_fall_back_to_a_search() {
+    // Neither a keyword nor a stem of one, but a prefix
+    let tokens = emojify_tokens("unicor").await;
+    assert_eq!(tokens[0].suggestions[0].emoji.as_str(), "🦄");
+    assert!(tokens[0].suggestions[0].confidence < 0.7);
+}
+
+#[tokio::test]
+async fn emojified_text_is_rendered_in_every_mode() {
+    let input = "I love pizza and cats";
+    assert_eq!(
+        render(input, EmojifyMode::Append).await,
+        "I love ❤️ pizza 🍕 and cats 🐈"
+    );
+    assert_eq!(render(input, EmojifyMode::Replace).await, "I ❤️ 🍕 and 🐈");
+    assert_eq!(
+        render(input, EmojifyMode::Suffix).await,
+        "I love pizza and cats ❤️🍕🐈"
+    );
+
+    // Punctuation around the words is kept
+    let input = "Cats, dogs... and (pizza)!";
+    assert_eq!(
+        render(input, EmojifyMode::Append).await,
+        "Cats 🐈, dogs 🐕... and (pizza 🍕)!"
+    );
+    assert_eq!(
+        render(input, EmojifyMode::Replace).await,
+        "🐈, 🐕... and (🍕)!"
+    );
+
+    // Nothing to add
+    assert_eq!(render("zzyzx", EmojifyMode::Suffix).await, "zzyzx");
+}
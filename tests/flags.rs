@@ -0,0 +1,42 @@
+// tests/flags.rs
+//! Country flags found by ISO 3166 code, name and demonym
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::search::resolve_flag;
+use emoji_search::search_emojis;
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+async fn flag_rank(input: &str, flag: &str) -> Option<usize> {
+    search_emojis(input, None, None, &EMOJI_DATA)
+        .await
+        .unwrap()
+        .iter()
+        .position(|emoji| emoji.as_str() == flag)
+}
+
+#[test]
+fn flags_resolve_by_code_name_and_demonym() {
+    for input in ["de", "DEU", "Germany", "german"] {
+        assert_eq!(resolve_flag(input).unwrap().as_str(), "🇩🇪", "{}", input);
+    }
+    assert_eq!(resolve_flag("côte d'ivoire").unwrap().as_str(), "🇨🇮");
+    assert_eq!(resolve_flag("zz"), None);
+}
+
+#[tokio::test]
+async fn code_flags_rank_above_prefix_matches() {
+    // "de" is an exact keyword of a few emojis, e.g. "fleur-de-lis", the flag comes next
+    assert!(flag_rank("de", "🇩🇪").await.is_some_and(|rank| rank < 5));
+    assert_eq!(flag_rank("fr", "🇫🇷").await, Some(0));
+    assert_eq!(flag_rank("fra", "🇫🇷").await, Some(0));
+    assert_eq!(flag_rank("ca", "🇨🇦").await, Some(0));
+}
+
+#[tokio::test]
+async fn name_flags_rank_first() {
+    assert_eq!(flag_rank("germany", "🇩🇪").await, Some(0));
+    assert_eq!(flag_rank("german", "🇩🇪").await, Some(0));
+}
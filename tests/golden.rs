@@ -0,0 +1,203 @@
+// tests/golden.rs
+//! Golden-file regression tests for ranking and text processing
+//!
+//! Each test runs the queries of `tests/golden/queries/` and compares the output with the
+//! checked-in `tests/golden/*.golden` file, one line per query, so any ranking change shows
+//! up as a reviewable diff of that file.
+//!
+//! After an intended change, run `UPDATE_GOLDEN=1 cargo test --test golden`, review the
+//! diff of the golden files and commit them. A missing golden file fails the test, it is
+//! only written with `UPDATE_GOLDEN=1`.
+
+use emoji_search::constants::{load_emoji_data, EmojiData, Options};
+use emoji_search::search::{match_emoji_to_words, match_emojis_to_word, match_emojis_to_words_raw};
+use emoji_search::utils::nlp::parts_of_speech::filter_parts_of_speech;
+use emoji_search::utils::nlp::stemmer::stem_word;
+use emoji_search::utils::preprocess::pre_process_string;
+use emoji_search::{search_best_matching_emojis, search_emojis};
+use emojis::emoji::Emoji;
+use once_cell::sync::Lazy;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Number of top results checked per query
+const TOP_N: usize = 10;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+/// Read the queries of a file in `tests/golden/queries/`, one per line
+fn read_queries(file_name: &str) -> Vec<String> {
+    let path = golden_dir().join("queries").join(file_name);
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Preprocess a query like the search entry points do
+fn normalize_query(query: &str) -> String {
+    pre_process_string(query).trim().to_string()
+}
+
+fn format_line(query: &str, output: &str) -> String {
+    format!("{:?} -> {}", query, output)
+}
+
+fn format_emojis(emojis: &[Emoji]) -> String {
+    emojis
+        .iter()
+        .take(TOP_N)
+        .map(|emoji| emoji.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Compare the lines with a golden file, or write it when updating
+fn check_golden(name: &str, lines: &[String]) {
+    let path = golden_dir().join(format!("{}.golden", name));
+    let actual = format!("{}\n", lines.join("\n"));
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &actual).expect("failed to write golden file");
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "{} is missing, run with UPDATE_GOLDEN=1 to create it, then review and commit it",
+            path.display()
+        );
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(i).copied().unwrap_or("<missing>");
+        let actual_line = actual_lines.get(i).copied().unwrap_or("<missing>");
+        if expected_line != actual_line {
+            diff.push_str(&format!("- {}\n+ {}\n", expected_line, actual_line));
+        }
+    }
+
+    panic!(
+        "{} differs from the golden file, run with UPDATE_GOLDEN=1 to accept:\n{}",
+        path.display(),
+        diff
+    );
+}
+
+#[tokio::test]
+async fn golden_match_emojis_to_word() {
+    let options = Options::default();
+    let mut lines = Vec::new();
+
+    for query in read_queries("single_word.txt") {
+        let emojis = match_emojis_to_word(&normalize_query(&query), &EMOJI_DATA, &options).await;
+        lines.push(format_line(&query, &format_emojis(&emojis)));
+    }
+
+    check_golden("match_emojis_to_word", &lines);
+}
+
+#[tokio::test]
+async fn golden_match_emojis_to_words_raw() {
+    let options = Options::default();
+    let mut lines = Vec::new();
+
+    for query in read_queries("multiple_words.txt") {
+        let emojis =
+            match_emojis_to_words_raw(&normalize_query(&query), &EMOJI_DATA, &options).await;
+        lines.push(format_line(&query, &format_emojis(&emojis)));
+    }
+
+    check_golden("match_emojis_to_words_raw", &lines);
+}
+
+#[tokio::test]
+async fn golden_match_emoji_to_words() {
+    let options = Options::default();
+    let mut lines = Vec::new();
+
+    for query in read_queries("multiple_words.txt") {
+        let emojis = match_emoji_to_words(&normalize_query(&query), &EMOJI_DATA, &options).await;
+        lines.push(format_line(&query, &format_emojis(&emojis)));
+    }
+
+    check_golden("match_emoji_to_words", &lines);
+}
+
+#[tokio::test]
+async fn golden_search_emojis() {
+    let mut lines = Vec::new();
+
+    for query in read_queries("multiple_words.txt") {
+        let emojis = search_emojis(&query, Some(TOP_N as u32), None, &EMOJI_DATA)
+            .await
+            .unwrap();
+        lines.push(format_line(&query, &format_emojis(&emojis)));
+    }
+
+    check_golden("search_emojis", &lines);
+}
+
+#[tokio::test]
+async fn golden_search_best_matching_emojis() {
+    let mut lines = Vec::new();
+
+    for query in read_queries("multiple_words.txt") {
+        let emojis = search_best_matching_emojis(&query, Some(TOP_N as u32), None, &EMOJI_DATA)
+            .await
+            .unwrap();
+        lines.push(format_line(&query, &format_emojis(&emojis)));
+    }
+
+    check_golden("search_best_matching_emojis", &lines);
+}
+
+#[test]
+fn golden_stem_word() {
+    let lines: Vec<String> = read_queries("stem_words.txt")
+        .iter()
+        .map(|word| format_line(word, &stem_word(word)))
+        .collect();
+
+    check_golden("stem_word", &lines);
+}
+
+#[test]
+fn golden_filter_parts_of_speech() {
+    let lines: Vec<String> = read_queries("sentences.txt")
+        .iter()
+        .map(|sentence| {
+            let words: Vec<String> = normalize_query(sentence)
+                .split_whitespace()
+                .map(|word| word.to_string())
+                .collect();
+            format_line(sentence, &filter_parts_of_speech(&words).join(" "))
+        })
+        .collect();
+
+    check_golden("filter_parts_of_speech", &lines);
+}
+
+#[test]
+fn golden_pre_process_string() {
+    let lines: Vec<String> = read_queries("raw_strings.txt")
+        .iter()
+        .map(|s| format_line(s, &format!("{:?}", pre_process_string(s))))
+        .collect();
+
+    check_golden("pre_process_string", &lines);
+}
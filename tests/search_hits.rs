@@ -0,0 +1,59 @@
+// tests/search_hits.rs
+//! Matched keywords returned with search results
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::search::SearchHit;
+use emoji_search::{search_best_matching_hits, search_hits};
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+fn find_hit<'a>(hits: &'a [SearchHit], emoji: &str) -> &'a SearchHit {
+    hits.iter()
+        .find(|hit| hit.emoji.as_str() == emoji)
+        .unwrap_or_else(|| panic!("{} is not in the results", emoji))
+}
+
+#[tokio::test]
+async fn single_word_hits_have_the_matched_keyword() {
+    let hits = search_hits("dog", None, None, &EMOJI_DATA).await.unwrap();
+    assert_eq!(
+        find_hit(&hits, "🌭").matched_keyword.as_deref(),
+        Some("hot dog")
+    );
+
+    // Prefix matches keep the whole keyword
+    let hits = search_hits("pupp", None, None, &EMOJI_DATA).await.unwrap();
+    assert_eq!(
+        find_hit(&hits, "🐶").matched_keyword.as_deref(),
+        Some("puppy")
+    );
+}
+
+#[tokio::test]
+async fn multiple_words_hits_have_the_matched_keyword() {
+    let hits = search_hits("red heart", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert_eq!(hits[0].emoji.as_str(), "❤️");
+    assert_eq!(hits[0].matched_keyword.as_deref(), Some("red heart"));
+
+    let hits = search_best_matching_hits("running dogs", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert!(hits.iter().all(|hit| hit.matched_keyword.is_some()));
+}
+
+#[tokio::test]
+async fn described_emojis_have_no_matched_keyword() {
+    let hits = search_hits("woman firefighter medium skin", None, None, &EMOJI_DATA)
+        .await
+        .unwrap();
+    assert_eq!(hits[0].emoji.as_str(), "👩🏽‍🚒");
+    assert_eq!(hits[0].matched_keyword, None);
+
+    let hits = search_hits("🐶", None, None, &EMOJI_DATA).await.unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].matched_keyword, None);
+}
@@ -0,0 +1,53 @@
+// tests/suggest.rs
+//! Concepts and emojis suggested for whole messages
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::search::MessageSuggestions;
+use emoji_search::suggest_for_message;
+use once_cell::sync::Lazy;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+async fn suggest(input: &str) -> MessageSuggestions {
+    suggest_for_message(input, Some(3), None, &EMOJI_DATA)
+        .await
+        .unwrap()
+}
+
+fn phrases(suggestions: &MessageSuggestions) -> Vec<&str> {
+    suggestions
+        .concepts
+        .iter()
+        .map(|concept| concept.phrase.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn message_is_split_into_concepts() {
+    let suggestions = suggest("going to the beach with my dog").await;
+    assert_eq!(phrases(&suggestions), ["beach", "dog"]);
+    assert_eq!(suggestions.top[0].as_str(), "🏖️");
+    assert_eq!(suggestions.top[1].as_str(), "🐕");
+}
+
+#[tokio::test]
+async fn unknown_phrase_is_split_into_words() {
+    let suggestions = suggest("happy birthday cakes").await;
+    assert_eq!(phrases(&suggestions), ["happy", "birthday", "cakes"]);
+}
+
+#[tokio::test]
+async fn phrase_words_are_stemmed_separately() {
+    // "red apples" is found as "red apple", not as the stem of the whole phrase
+    let suggestions = suggest("red apples").await;
+    assert_eq!(phrases(&suggestions), ["red apples"]);
+    assert_eq!(suggestions.concepts[0].suggestions[0].emoji.as_str(), "🍎");
+}
+
+#[tokio::test]
+async fn unknown_words_have_no_concepts() {
+    let suggestions = suggest("zzyzx qwerty").await;
+    assert!(suggestions.concepts.is_empty());
+    assert!(suggestions.top.is_empty());
+}
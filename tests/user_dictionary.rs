@@ -0,0 +1,227 @@
+// tests/user_dictionary.rs
+//! Save and load round trips of user dictionaries, and removal of personal keywords
+
+use emoji_search::constants::{load_emoji_data, EmojiData};
+use emoji_search::user_dictionary::UserDictionary;
+use emojis::emoji::Emoji;
+use once_cell::sync::Lazy;
+use std::fs;
+use std::path::PathBuf;
+
+static EMOJI_DATA: Lazy<EmojiData> =
+    Lazy::new(|| load_emoji_data().expect("failed to load the embedded emoji data"));
+
+/// A path in a fresh temporary directory of the test
+fn temp_path(test_name: &str, file_name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("emoji_search_{}_{}", test_name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("failed to create the temporary directory");
+    dir.join(file_name)
+}
+
+fn emoji(s: &str) -> Emoji {
+    emojis::get(s).unwrap_or_else(|| panic!("{} is not an emoji", s))
+}
+
+fn keywords(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+fn has_keyword(emoji_data: &EmojiData, emoji: &Emoji, keyword: &str) -> bool {
+    emoji_data
+        .emoji_keywords
+        .get(emoji)
+        .is_some_and(|keywords| keywords.iter().any(|k| k == keyword))
+}
+
+fn check_round_trip(test_name: &str, file_name: &str) {
+    let path = temp_path(test_name, file_name);
+    let dog = emoji("🐶");
+    let party = emoji("🎉");
+    let mut emoji_data = EMOJI_DATA.clone();
+
+    let mut dictionary = UserDictionary::open(&path).expect("failed to open the dictionary");
+    dictionary
+        .add_keywords(&dog, &keywords(&["pupper", "doggo"]), &mut emoji_data)
+        .expect("failed to add keywords");
+    dictionary
+        .set_most_relevant("yay", &party, &mut emoji_data)
+        .expect("failed to set the most relevant emoji");
+
+    assert!(path.exists());
+    let mut tmp_name = path.file_name().unwrap().to_os_string();
+    tmp_name.push(".tmp");
+    assert!(!path.with_file_name(tmp_name).exists());
+
+    let mut reopened = UserDictionary::open(&path).expect("failed to reopen the dictionary");
+    assert_eq!(
+        reopened.keywords(&dog),
+        keywords(&["pupper", "doggo"]).as_slice()
+    );
+
+    let mut reloaded_data = EMOJI_DATA.clone();
+    reopened.merge_into(&mut reloaded_data);
+    assert!(has_keyword(&reloaded_data, &dog, "pupper"));
+    assert!(has_keyword(&reloaded_data, &dog, "doggo"));
+    assert_eq!(
+        reloaded_data.keyword_most_relevant_emoji.get("yay"),
+        Some(&party)
+    );
+
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn json_round_trip() {
+    let path = temp_path("json_format", "words.json");
+    check_round_trip("json_round_trip", "words.json");
+
+    // JSON files stay human readable
+    let mut dictionary = UserDictionary::open(&path).unwrap();
+    dictionary
+        .add_keywords(
+            &emoji("🐶"),
+            &keywords(&["pupper"]),
+            &mut EMOJI_DATA.clone(),
+        )
+        .unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("pupper"));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn cbor_round_trip() {
+    check_round_trip("cbor_round_trip", "words.cbor");
+}
+
+#[test]
+fn json_and_cbor_dictionaries_side_by_side() {
+    let json_path = temp_path("side_by_side", "words.json");
+    let cbor_path = json_path.with_extension("cbor");
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+
+    let mut json_dictionary = UserDictionary::open(&json_path).unwrap();
+    let mut cbor_dictionary = UserDictionary::open(&cbor_path).unwrap();
+    json_dictionary
+        .add_keywords(&dog, &keywords(&["pupper"]), &mut emoji_data)
+        .unwrap();
+    cbor_dictionary
+        .add_keywords(&dog, &keywords(&["doggo"]), &mut emoji_data)
+        .unwrap();
+
+    let json_dictionary = UserDictionary::open(&json_path).unwrap();
+    let cbor_dictionary = UserDictionary::open(&cbor_path).unwrap();
+    assert_eq!(
+        json_dictionary.keywords(&dog),
+        keywords(&["pupper"]).as_slice()
+    );
+    assert_eq!(
+        cbor_dictionary.keywords(&dog),
+        keywords(&["doggo"]).as_slice()
+    );
+
+    fs::remove_dir_all(json_path.parent().unwrap()).ok();
+}
+
+#[test]
+fn remove_personal_keyword() {
+    let path = temp_path("remove_personal_keyword", "words.json");
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+
+    let mut dictionary = UserDictionary::open(&path).unwrap();
+    dictionary
+        .add_keywords(&dog, &keywords(&["pupper", "doggo"]), &mut emoji_data)
+        .unwrap();
+
+    assert!(dictionary
+        .remove_keyword(&dog, "Pupper", &mut emoji_data)
+        .unwrap());
+    assert!(!has_keyword(&emoji_data, &dog, "pupper"));
+    assert!(has_keyword(&emoji_data, &dog, "doggo"));
+    assert_eq!(dictionary.keywords(&dog), keywords(&["doggo"]).as_slice());
+
+    // Removed from the file too
+    let reopened = UserDictionary::open(&path).unwrap();
+    assert_eq!(reopened.keywords(&dog), keywords(&["doggo"]).as_slice());
+
+    // Keywords the dictionary doesn't have aren't removed
+    assert!(!dictionary
+        .remove_keyword(&dog, "pupper", &mut emoji_data)
+        .unwrap());
+    assert!(!dictionary
+        .remove_keyword(&dog, "puppy", &mut emoji_data)
+        .unwrap());
+    assert!(has_keyword(&emoji_data, &dog, "puppy"));
+
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn remove_personal_keyword_keeps_built_in_keyword() {
+    let path = temp_path("keeps_built_in_keyword", "words.json");
+    let dog = emoji("🐶");
+    let mut emoji_data = EMOJI_DATA.clone();
+    assert!(has_keyword(&emoji_data, &dog, "puppy"));
+
+    let mut dictionary = UserDictionary::open(&path).unwrap();
+    dictionary
+        .add_keywords(&dog, &keywords(&["Puppy", "pupper"]), &mut emoji_data)
+        .unwrap();
+
+    assert!(dictionary
+        .remove_keyword(&dog, "Puppy", &mut emoji_data)
+        .unwrap());
+    assert!(has_keyword(&emoji_data, &dog, "puppy"));
+    assert!(dictionary.keywords(&dog).iter().all(|k| k != "Puppy"));
+
+    // The same holds after merging a reopened dictionary
+    let mut reopened = UserDictionary::open(&path).unwrap();
+    let mut reloaded_data = EMOJI_DATA.clone();
+    reopened
+        .add_keywords(&dog, &keywords(&["puppy"]), &mut reloaded_data)
+        .unwrap();
+    reopened.merge_into(&mut reloaded_data);
+    assert!(reopened
+        .remove_keyword(&dog, "puppy", &mut reloaded_data)
+        .unwrap());
+    assert!(has_keyword(&reloaded_data, &dog, "puppy"));
+    assert!(reopened
+        .remove_keyword(&dog, "pupper", &mut reloaded_data)
+        .unwrap());
+    assert!(!has_keyword(&reloaded_data, &dog, "pupper"));
+
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn shortcode_entries_are_merged() {
+    let path = temp_path("shortcode_entries", "words.json");
+    let dog = emoji("🐶");
+    let party = emoji("🎉");
+    let dog_shortcode = dog.shortcodes().next().expect("🐶 has no shortcode");
+    let party_shortcode = party.shortcodes().next().expect("🎉 has no shortcode");
+    fs::write(
+        &path,
+        format!(
+            r#"{{"emoji_keywords": {{"{}": ["pupper"]}}, "keyword_most_relevant_emoji": {{"yay": "{}"}}}}"#,
+            dog_shortcode, party_shortcode
+        ),
+    )
+    .unwrap();
+
+    let mut emoji_data = EMOJI_DATA.clone();
+    let mut dictionary = UserDictionary::open(&path).unwrap();
+    dictionary.merge_into(&mut emoji_data);
+
+    assert!(has_keyword(&emoji_data, &dog, "pupper"));
+    assert_eq!(
+        emoji_data.keyword_most_relevant_emoji.get("yay"),
+        Some(&party)
+    );
+
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
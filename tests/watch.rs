@@ -0,0 +1,61 @@
+// tests/watch.rs
+//! Hot reload of watched data files into the global emoji data, run with
+//! `cargo test --features watch --test watch`
+
+#![cfg(feature = "watch")]
+
+use emoji_search::constants::EmojiDataPaths;
+use emoji_search::global::global_emoji_data;
+use emoji_search::watch::watch_emoji_data;
+use std::fs;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time to wait for a reload, well above the debounce of the watcher
+const RELOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn num_global_emoji_keywords() -> usize {
+    global_emoji_data().unwrap().emoji_keywords.len()
+}
+
+#[test]
+fn files_without_valid_entries_are_rejected() {
+    let dir = std::env::temp_dir().join(format!("emoji-search-watch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("emoji-keywords.json");
+    fs::write(&path, r#"{"🐶": ["doggo"], "🐱": ["kitty"]}"#).unwrap();
+
+    let (tx, rx) = channel();
+    let paths = EmojiDataPaths {
+        emoji_keywords: Some(path.clone()),
+        ..EmojiDataPaths::default()
+    };
+    let _watcher = watch_emoji_data(paths, move |path, e| {
+        tx.send((path.to_path_buf(), e.to_string())).ok();
+    })
+    .unwrap();
+    assert_eq!(num_global_emoji_keywords(), 2);
+
+    // An empty file and a file of unknown emojis keep the previous data
+    for json in ["{}", r#"{"not an emoji": ["nope"]}"#] {
+        fs::write(&path, json).unwrap();
+        let (error_path, message) = rx
+            .recv_timeout(RELOAD_TIMEOUT)
+            .unwrap_or_else(|_| panic!("{} was not rejected", json));
+        assert_eq!(error_path.file_name(), path.file_name());
+        assert!(message.contains("no valid entries"), "{}", message);
+        assert_eq!(num_global_emoji_keywords(), 2);
+    }
+
+    // A file with a single valid entry is reloaded
+    fs::write(&path, r#"{"🐶": ["doggo"], "not an emoji": ["nope"]}"#).unwrap();
+    let start = Instant::now();
+    while num_global_emoji_keywords() != 1 {
+        assert!(start.elapsed() < RELOAD_TIMEOUT, "file was not reloaded");
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert!(rx.try_recv().is_err());
+
+    fs::remove_dir_all(&dir).ok();
+}